@@ -4,4 +4,6 @@
 extern crate lalrpop_util;
 
 pub mod sql_parser;
+// The plan nodes are a sketch that nothing builds yet
+#[allow(dead_code)]
 pub mod plan;
\ No newline at end of file
@@ -1,9 +1,11 @@
 use std::fmt;
 use crate::sql_parser::ast::node::{NodeTrait, Node};
-use crate::sql_parser::ast::expression::Expression;
+use crate::sql_parser::ast::expression::{map_sort_items, map_window, write_predicate_operand, Expression, FunctionArgument,
+                                         FunctionCall, WindowDefinition};
 use itertools::join;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
 pub enum Statement {
     Query {
         with: Option<With>,
@@ -29,17 +31,58 @@ pub enum Statement {
         table_name: QualifiedName,
         if_not_exists: bool,
         columns: Option<Vec<ColumnName>>,
-        query: Box<Statement>
+        comment: Option<String>,
+        properties: Vec<Property>,
+        query: Box<Statement>,
+        // `with data` is Some(true), `with no data` Some(false)
+        with_data: Option<bool>
     },
     CreateTable {
         table_name: QualifiedName,
         if_not_exists: bool,
-        table_elements: Vec<TableElement>
+        table_elements: Vec<TableElement>,
+        comment: Option<String>,
+        properties: Vec<Property>
     },
     DropTable {
         table_name: QualifiedName,
         if_exists: bool
     },
+    RenameTable {
+        from: QualifiedName,
+        to: QualifiedName,
+        if_exists: bool
+    },
+    AddColumn {
+        table_name: QualifiedName,
+        if_exists: bool,
+        column_if_not_exists: bool,
+        column: ColumnDefinition
+    },
+    DropColumn {
+        table_name: QualifiedName,
+        if_exists: bool,
+        column_if_exists: bool,
+        column: String
+    },
+    RenameColumn {
+        table_name: QualifiedName,
+        if_exists: bool,
+        column_if_exists: bool,
+        from: String,
+        to: String
+    },
+    SetColumnType {
+        table_name: QualifiedName,
+        if_exists: bool,
+        column: String,
+        column_type: Type
+    },
+    SetTableProperties {
+        table_name: QualifiedName,
+        if_exists: bool,
+        properties: Vec<Property>
+    },
     InsertInto {
         table_name: QualifiedName,
         columns: Option<Vec<ColumnName>>,
@@ -49,41 +92,1081 @@ pub enum Statement {
         from: QualifiedName,
         filter: Option<Expression>
     },
+    Update {
+        table_name: QualifiedName,
+        assignments: Vec<UpdateAssignment>,
+        filter: Option<Expression>
+    },
+    Merge {
+        table_name: QualifiedName,
+        alias: Option<String>,
+        source: Relation,
+        condition: Expression,
+        cases: Vec<MergeCase>
+    },
+    CreateView {
+        view_name: QualifiedName,
+        or_replace: bool,
+        columns: Option<Vec<ColumnName>>,
+        query: Box<Statement>
+    },
+    DropView {
+        view_name: QualifiedName,
+        if_exists: bool
+    },
+    AlterView {
+        from: QualifiedName,
+        to: QualifiedName
+    },
+    CreateMaterializedView {
+        view_name: QualifiedName,
+        or_replace: bool,
+        if_not_exists: bool,
+        query: Box<Statement>
+    },
+    RefreshMaterializedView {
+        view_name: QualifiedName
+    },
+    StartTransaction {
+        modes: Vec<TransactionMode>
+    },
+    Begin,
+    Commit,
+    Rollback,
+    Savepoint {
+        name: String
+    },
+    ReleaseSavepoint {
+        name: String
+    },
+    RollbackToSavepoint {
+        name: String
+    },
+    Explain {
+        analyze: bool,
+        verbose: bool,
+        options: Vec<ExplainOption>,
+        statement: Box<Statement>
+    },
+    ShowCatalogs {
+        like: Option<LikePattern>
+    },
+    ShowSchemas {
+        catalog: Option<QualifiedName>,
+        like: Option<LikePattern>
+    },
+    ShowTables {
+        schema: Option<QualifiedName>,
+        like: Option<LikePattern>
+    },
+    ShowColumns {
+        table_name: QualifiedName,
+        like: Option<LikePattern>
+    },
+    ShowFunctions {
+        like: Option<LikePattern>
+    },
+    ShowCreate {
+        object_type: ShowCreateType,
+        name: QualifiedName
+    },
+    ShowSession {
+        like: Option<LikePattern>
+    },
+    ShowStats {
+        table_name: QualifiedName
+    },
+    Describe {
+        table_name: QualifiedName
+    },
+    Prepare {
+        name: String,
+        statement: Box<Statement>
+    },
+    Execute {
+        name: String,
+        parameters: Vec<Expression>
+    },
+    Deallocate {
+        name: String
+    },
+    DescribeInput {
+        name: String
+    },
+    DescribeOutput {
+        name: String
+    },
+}
+
+impl Statement {
+    // Rebuilds the statement with `f` applied to each expression it holds, those of nested
+    // queries included. `f` gets whole expressions and descends into them as it needs.
+    pub fn map_expressions<F: FnMut(Expression) -> Expression>(self, f: &mut F) -> Statement {
+        match self {
+            Statement::Query {
+                with, body
+            } => Statement::Query {
+                with: with.map(|with| With {
+                    recursive: with.recursive,
+                    body: with.body.into_iter().map(|named_query| NamedQuery {
+                        body: map_query(named_query.body, f), ..named_query
+                    }).collect()
+                }),
+                body: map_query_body(body, f)
+            },
+            Statement::CreateTableAsSelect {
+                table_name, if_not_exists, columns, comment, properties, query, with_data
+            } => Statement::CreateTableAsSelect {
+                table_name, if_not_exists, columns, comment, properties: map_properties(properties, f),
+                query: map_query(query, f), with_data
+            },
+            Statement::CreateTable {
+                table_name, if_not_exists, table_elements, comment, properties
+            } => Statement::CreateTable {
+                table_name, if_not_exists,
+                table_elements: table_elements.into_iter().map(|element| match element {
+                    TableElement::ColumnDefinition(column) =>
+                        TableElement::ColumnDefinition(map_column_definition(column, f)),
+                    TableElement::Constraint(TableConstraint::Check(check)) =>
+                        TableElement::Constraint(TableConstraint::Check(f(check))),
+                    element => element
+                }).collect(),
+                comment, properties: map_properties(properties, f)
+            },
+            Statement::AddColumn {
+                table_name, if_exists, column_if_not_exists, column
+            } => Statement::AddColumn {
+                table_name, if_exists, column_if_not_exists, column: map_column_definition(column, f)
+            },
+            Statement::SetTableProperties {
+                table_name, if_exists, properties
+            } => Statement::SetTableProperties {
+                table_name, if_exists, properties: map_properties(properties, f)
+            },
+            Statement::InsertInto {
+                table_name, columns, query
+            } => Statement::InsertInto {
+                table_name, columns, query: map_query(query, f)
+            },
+            Statement::Delete {
+                from, filter
+            } => Statement::Delete {
+                from, filter: filter.map(&mut *f)
+            },
+            Statement::Update {
+                table_name, assignments, filter
+            } => Statement::Update {
+                table_name, assignments: map_assignments(assignments, f), filter: filter.map(&mut *f)
+            },
+            Statement::Merge {
+                table_name, alias, source, condition, cases
+            } => Statement::Merge {
+                table_name,
+                alias,
+                source: map_relation(source, f),
+                condition: f(condition),
+                cases: cases.into_iter().map(|case| match case {
+                    MergeCase::Update { condition, assignments } => MergeCase::Update {
+                        condition: condition.map(&mut *f), assignments: map_assignments(assignments, f)
+                    },
+                    MergeCase::Delete { condition } => MergeCase::Delete {
+                        condition: condition.map(&mut *f)
+                    },
+                    MergeCase::Insert { condition, columns, values } => MergeCase::Insert {
+                        condition: condition.map(&mut *f), columns, values: values.into_iter().map(&mut *f).collect()
+                    }
+                }).collect()
+            },
+            Statement::CreateView {
+                view_name, or_replace, columns, query
+            } => Statement::CreateView {
+                view_name, or_replace, columns, query: map_query(query, f)
+            },
+            Statement::CreateMaterializedView {
+                view_name, or_replace, if_not_exists, query
+            } => Statement::CreateMaterializedView {
+                view_name, or_replace, if_not_exists, query: map_query(query, f)
+            },
+            Statement::Explain {
+                analyze, verbose, options, statement
+            } => Statement::Explain {
+                analyze, verbose, options, statement: map_query(statement, f)
+            },
+            Statement::Prepare {
+                name, statement
+            } => Statement::Prepare {
+                name, statement: map_query(statement, f)
+            },
+            Statement::Execute {
+                name, parameters
+            } => Statement::Execute {
+                name, parameters: parameters.into_iter().map(f).collect()
+            },
+            statement => statement
+        }
+    }
+
+    // Replaces each parameter with the value at its position, there must be exactly one value per parameter
+    pub fn bind(self, values: &[Expression]) -> Result<Statement, BindError> {
+        let mut expected = 0;
+        let statement = self.map_expressions(&mut |expression| bind_parameters(expression, values, &mut expected));
+        if expected == values.len() {
+            Ok(statement)
+        } else {
+            Err(BindError::ParameterCount { expected, given: values.len() })
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BindError {
+    // the statement has `expected` parameters but `given` values were bound
+    ParameterCount {
+        expected: usize,
+        given: usize
+    }
+}
+
+impl fmt::Display for BindError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BindError::ParameterCount {
+                expected, given
+            } => write!(f, "expected {} parameter values but {} were given", expected, given)
+        }
+    }
+}
+
+// `expected` ends up one past the highest parameter position seen, the number of distinct parameters
+// when they are numbered without gaps
+fn bind_parameters(expression: Expression, values: &[Expression], expected: &mut usize) -> Expression {
+    match expression {
+        Expression::Parameter { position, name } => {
+            *expected = (*expected).max(position + 1);
+            match values.get(position) {
+                Some(value) => value.clone(),
+                None => Expression::Parameter { position, name }
+            }
+        },
+        expression => {
+            let expression = expression.map_children(&mut |child| bind_parameters(child, values, expected));
+            expression.map_subqueries(&mut |subquery| {
+                subquery.map_expressions(&mut |child| bind_parameters(child, values, expected))
+            })
+        }
+    }
+}
+
+fn map_query<F: FnMut(Expression) -> Expression>(mut query: Box<Statement>, f: &mut F) -> Box<Statement> {
+    *query = query.map_expressions(f);
+    query
+}
+
+fn map_query_body<F: FnMut(Expression) -> Expression>(body: QueryBody, f: &mut F) -> QueryBody {
+    QueryBody {
+        query_term: map_query_term(body.query_term, f),
+        order_by: body.order_by.map(|order_by| map_sort_items(order_by, f)),
+        offset: body.offset.map(|offset| Offset {
+            row_count: f(offset.row_count), rows: offset.rows
+        }),
+        limit: body.limit.map(|limit| match limit {
+            Limit::Count(count) => Limit::Count(f(count)),
+            Limit::All => Limit::All,
+            Limit::CountOffset { count, offset } => Limit::CountOffset {
+                count: f(count), offset: f(offset)
+            },
+            Limit::OffsetCount { offset, count } => Limit::OffsetCount {
+                offset: f(offset), count: f(count)
+            },
+            Limit::Fetch { next, count, rows, with_ties } => Limit::Fetch {
+                next, count: count.map(&mut *f), rows, with_ties
+            }
+        })
+    }
+}
+
+fn map_query_term<F: FnMut(Expression) -> Expression>(query_term: QueryTerm, f: &mut F) -> QueryTerm {
+    match query_term {
+        QueryTerm::Primary(QueryPrimary::Select(select)) => QueryTerm::Primary(QueryPrimary::Select(Select {
+            distinctness: select.distinctness,
+            projection: select.projection.into_iter().map(|item| match item {
+                SelectItem::SingleColumn { expression, alias } => SelectItem::SingleColumn {
+                    expression: f(expression), alias
+                },
+                item => item
+            }).collect(),
+            from: select.from.map(|from| map_relation(from, f)),
+            filter: select.filter.map(&mut *f),
+            group_by: select.group_by.map(|group_by| GroupBy {
+                distinct: group_by.distinct,
+                elements: group_by.elements.into_iter().map(|element| match element {
+                    GroupingElement::Simple(set) => GroupingElement::Simple(set.into_iter().map(&mut *f).collect()),
+                    GroupingElement::Rollup(set) => GroupingElement::Rollup(set.into_iter().map(&mut *f).collect()),
+                    GroupingElement::Cube(set) => GroupingElement::Cube(set.into_iter().map(&mut *f).collect()),
+                    GroupingElement::GroupingSets(sets) => GroupingElement::GroupingSets(sets.into_iter().map(|set| {
+                        set.into_iter().map(&mut *f).collect()
+                    }).collect())
+                }).collect()
+            }),
+            having: select.having.map(&mut *f),
+            windows: select.windows.map(|windows| windows.into_iter().map(|definition| WindowDefinition {
+                name: definition.name, window: map_window(definition.window, f)
+            }).collect())
+        })),
+        QueryTerm::Primary(QueryPrimary::Values(rows)) => {
+            let rows = rows.into_iter().map(|row| row.into_iter().map(&mut *f).collect()).collect();
+            QueryTerm::Primary(QueryPrimary::Values(rows))
+        },
+        QueryTerm::Primary(QueryPrimary::Subquery(mut body)) => {
+            *body = map_query_body(*body, f);
+            QueryTerm::Primary(QueryPrimary::Subquery(body))
+        },
+        QueryTerm::SetOperation { left, operator, distinct, right } => QueryTerm::SetOperation {
+            left: Box::new(map_query_term(*left, f)), operator, distinct, right: Box::new(map_query_term(*right, f))
+        }
+    }
+}
+
+fn map_relation<F: FnMut(Expression) -> Expression>(relation: Relation, f: &mut F) -> Relation {
+    match relation {
+        Relation::Table(name) => Relation::Table(name),
+        Relation::Subquery(query) => Relation::Subquery(map_query(query, f)),
+        Relation::Aliased { relation, alias, columns } => Relation::Aliased {
+            relation: Box::new(map_relation(*relation, f)), alias, columns
+        },
+        Relation::Join { join_type, left, right, criteria } => Relation::Join {
+            join_type,
+            left: Box::new(map_relation(*left, f)),
+            right: Box::new(map_relation(*right, f)),
+            criteria: criteria.map(|criteria| match criteria {
+                JoinCriteria::On(condition) => JoinCriteria::On(f(condition)),
+                criteria => criteria
+            })
+        },
+        Relation::Unnest { expressions, with_ordinality } => Relation::Unnest {
+            expressions: expressions.into_iter().map(f).collect(), with_ordinality
+        },
+        Relation::Lateral(query) => Relation::Lateral(map_query(query, f)),
+        Relation::Sampled { relation, sample_type, mut percentage } => {
+            *percentage = f(*percentage);
+            Relation::Sampled {
+                relation: Box::new(map_relation(*relation, f)), sample_type, percentage
+            }
+        }
+    }
+}
+
+fn map_assignments<F: FnMut(Expression) -> Expression>(assignments: Vec<UpdateAssignment>,
+                                                      f: &mut F) -> Vec<UpdateAssignment> {
+    assignments.into_iter().map(|assignment| UpdateAssignment {
+        name: assignment.name, value: f(assignment.value)
+    }).collect()
+}
+
+fn map_properties<F>(properties: Vec<Property>, f: &mut F) -> Vec<Property>
+    where F: FnMut(Expression) -> Expression
+{
+    properties.into_iter().map(|property| Property {
+        name: property.name, value: f(property.value)
+    }).collect()
+}
+
+fn map_column_definition<F>(column: ColumnDefinition, f: &mut F) -> ColumnDefinition
+    where F: FnMut(Expression) -> Expression
+{
+    ColumnDefinition {
+        default: column.default.map(&mut *f),
+        properties: map_properties(column.properties, f),
+        ..column
+    }
+}
+
+fn if_exists(if_exists: bool) -> &'static str {
+    if if_exists { "if exists " } else { "" }
+}
+
+fn if_not_exists(if_not_exists: bool) -> &'static str {
+    if if_not_exists { "if not exists " } else { "" }
+}
+
+fn or_replace(or_replace: bool) -> &'static str {
+    if or_replace { "or replace " } else { "" }
+}
+
+fn write_like(f: &mut fmt::Formatter, like: &Option<LikePattern>) -> fmt::Result {
+    match like {
+        Some(like) => write!(f, " {}", like),
+        None => Ok(())
+    }
+}
+
+fn write_comment(f: &mut fmt::Formatter, comment: &Option<String>) -> fmt::Result {
+    match comment {
+        Some(comment) => write!(f, " comment '{}'", comment),
+        None => Ok(())
+    }
+}
+
+fn write_properties(f: &mut fmt::Formatter, properties: &[Property]) -> fmt::Result {
+    if properties.is_empty() {
+        return Ok(());
+    }
+    write!(f, " with ({})", join(properties, ", "))
+}
+
+fn write_column_aliases(f: &mut fmt::Formatter, columns: &Option<Vec<ColumnName>>) -> fmt::Result {
+    match columns {
+        Some(columns) => write!(f, " ({})", join(columns, ", ")),
+        None => Ok(())
+    }
+}
+
+impl fmt::Display for Statement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Statement::Query {
+                with, body
+            } => {
+                if let Some(with) = with {
+                    write!(f, "{} ", with)?;
+                }
+                write!(f, "{}", body)
+            },
+            Statement::Use {
+                schema
+            } => write!(f, "use {}", schema),
+            Statement::CreateSchema {
+                schema, if_not_exists: not_exists
+            } => write!(f, "create schema {}{}", if_not_exists(*not_exists), schema),
+            Statement::AlterSchema {
+                from, to
+            } => write!(f, "alter schema {} rename to {}", from, to),
+            Statement::DropSchema {
+                schema, if_exists: exists, prop
+            } => {
+                write!(f, "drop schema {}{}", if_exists(*exists), schema)?;
+                match prop {
+                    Some(prop) => write!(f, " {}", prop),
+                    None => Ok(())
+                }
+            },
+            Statement::CreateTableAsSelect {
+                table_name, if_not_exists: not_exists, columns, comment, properties, query, with_data
+            } => {
+                write!(f, "create table {}{}", if_not_exists(*not_exists), table_name)?;
+                write_column_aliases(f, columns)?;
+                write_comment(f, comment)?;
+                write_properties(f, properties)?;
+                write!(f, " as {}", query)?;
+                match with_data {
+                    Some(true) => write!(f, " with data"),
+                    Some(false) => write!(f, " with no data"),
+                    None => Ok(())
+                }
+            },
+            Statement::CreateTable {
+                table_name, if_not_exists: not_exists, table_elements, comment, properties
+            } => {
+                write!(f, "create table {}{}", if_not_exists(*not_exists), table_name)?;
+                write!(f, " ({})", join(table_elements, ", "))?;
+                write_comment(f, comment)?;
+                write_properties(f, properties)
+            },
+            Statement::DropTable {
+                table_name, if_exists: exists
+            } => write!(f, "drop table {}{}", if_exists(*exists), table_name),
+            Statement::RenameTable {
+                from, to, if_exists: exists
+            } => write!(f, "alter table {}{} rename to {}", if_exists(*exists), from, to),
+            Statement::AddColumn {
+                table_name, if_exists: exists, column_if_not_exists, column
+            } => write!(f, "alter table {}{} add column {}{}", if_exists(*exists), table_name,
+                        if_not_exists(*column_if_not_exists), column),
+            Statement::DropColumn {
+                table_name, if_exists: exists, column_if_exists, column
+            } => write!(f, "alter table {}{} drop column {}{}", if_exists(*exists), table_name,
+                        if_exists(*column_if_exists), column),
+            Statement::RenameColumn {
+                table_name, if_exists: exists, column_if_exists, from, to
+            } => write!(f, "alter table {}{} rename column {}{} to {}", if_exists(*exists), table_name,
+                        if_exists(*column_if_exists), from, to),
+            Statement::SetColumnType {
+                table_name, if_exists: exists, column, column_type
+            } => write!(f, "alter table {}{} alter column {} set data type {}", if_exists(*exists), table_name,
+                        column, column_type),
+            Statement::SetTableProperties {
+                table_name, if_exists: exists, properties
+            } => write!(f, "alter table {}{} set properties {}", if_exists(*exists), table_name,
+                        join(properties, ", ")),
+            Statement::InsertInto {
+                table_name, columns, query
+            } => {
+                write!(f, "insert into {}", table_name)?;
+                write_column_aliases(f, columns)?;
+                write!(f, " {}", query)
+            },
+            Statement::Delete {
+                from, filter
+            } => {
+                write!(f, "delete from {}", from)?;
+                match filter {
+                    Some(filter) => write!(f, " where {}", filter),
+                    None => Ok(())
+                }
+            },
+            Statement::Update {
+                table_name, assignments, filter
+            } => {
+                write!(f, "update {} set {}", table_name, join(assignments, ", "))?;
+                match filter {
+                    Some(filter) => write!(f, " where {}", filter),
+                    None => Ok(())
+                }
+            },
+            Statement::Merge {
+                table_name, alias, source, condition, cases
+            } => {
+                write!(f, "merge into {}", table_name)?;
+                if let Some(alias) = alias {
+                    write!(f, " as {}", alias)?;
+                }
+                write!(f, " using {} on {}", source, condition)?;
+                for case in cases {
+                    write!(f, " {}", case)?;
+                }
+                Ok(())
+            },
+            Statement::CreateView {
+                view_name, or_replace: replace, columns, query
+            } => {
+                write!(f, "create {}view {}", or_replace(*replace), view_name)?;
+                write_column_aliases(f, columns)?;
+                write!(f, " as {}", query)
+            },
+            Statement::DropView {
+                view_name, if_exists: exists
+            } => write!(f, "drop view {}{}", if_exists(*exists), view_name),
+            Statement::AlterView {
+                from, to
+            } => write!(f, "alter view {} rename to {}", from, to),
+            Statement::CreateMaterializedView {
+                view_name, or_replace: replace, if_not_exists: not_exists, query
+            } => write!(f, "create {}materialized view {}{} as {}", or_replace(*replace), if_not_exists(*not_exists),
+                        view_name, query),
+            Statement::RefreshMaterializedView {
+                view_name
+            } => write!(f, "refresh materialized view {}", view_name),
+            Statement::StartTransaction {
+                modes
+            } => {
+                write!(f, "start transaction")?;
+                if !modes.is_empty() {
+                    write!(f, " {}", join(modes, ", "))?;
+                }
+                Ok(())
+            },
+            Statement::Begin => write!(f, "begin"),
+            Statement::Commit => write!(f, "commit"),
+            Statement::Rollback => write!(f, "rollback"),
+            Statement::Savepoint {
+                name
+            } => write!(f, "savepoint {}", name),
+            Statement::ReleaseSavepoint {
+                name
+            } => write!(f, "release savepoint {}", name),
+            Statement::RollbackToSavepoint {
+                name
+            } => write!(f, "rollback to savepoint {}", name),
+            Statement::Explain {
+                analyze, verbose, options, statement
+            } => {
+                write!(f, "explain ")?;
+                if *analyze {
+                    write!(f, "analyze ")?;
+                }
+                if *verbose {
+                    write!(f, "verbose ")?;
+                }
+                if !options.is_empty() {
+                    write!(f, "({}) ", join(options, ", "))?;
+                }
+                write!(f, "{}", statement)
+            },
+            Statement::ShowCatalogs {
+                like
+            } => {
+                write!(f, "show catalogs")?;
+                write_like(f, like)
+            },
+            Statement::ShowSchemas {
+                catalog, like
+            } => {
+                write!(f, "show schemas")?;
+                if let Some(catalog) = catalog {
+                    write!(f, " from {}", catalog)?;
+                }
+                write_like(f, like)
+            },
+            Statement::ShowTables {
+                schema, like
+            } => {
+                write!(f, "show tables")?;
+                if let Some(schema) = schema {
+                    write!(f, " from {}", schema)?;
+                }
+                write_like(f, like)
+            },
+            Statement::ShowColumns {
+                table_name, like
+            } => {
+                write!(f, "show columns from {}", table_name)?;
+                write_like(f, like)
+            },
+            Statement::ShowFunctions {
+                like
+            } => {
+                write!(f, "show functions")?;
+                write_like(f, like)
+            },
+            Statement::ShowCreate {
+                object_type, name
+            } => write!(f, "show create {} {}", object_type, name),
+            Statement::ShowSession {
+                like
+            } => {
+                write!(f, "show session")?;
+                write_like(f, like)
+            },
+            Statement::ShowStats {
+                table_name
+            } => write!(f, "show stats for {}", table_name),
+            Statement::Describe {
+                table_name
+            } => write!(f, "describe {}", table_name),
+            Statement::Prepare {
+                name, statement
+            } => write!(f, "prepare {} from {}", name, statement),
+            Statement::Execute {
+                name, parameters
+            } => {
+                write!(f, "execute {}", name)?;
+                if !parameters.is_empty() {
+                    write!(f, " using {}", join(parameters, ", "))?;
+                }
+                Ok(())
+            },
+            Statement::Deallocate {
+                name
+            } => write!(f, "deallocate prepare {}", name),
+            Statement::DescribeInput {
+                name
+            } => write!(f, "describe input {}", name),
+            Statement::DescribeOutput {
+                name
+            } => write!(f, "describe output {}", name),
+        }
+    }
+}
+
+// LIKE 'pattern' [ESCAPE 'escape'] filter of a SHOW statement, with both strings kept as written
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LikePattern {
+    pub pattern: String,
+    pub escape: Option<String>
+}
+
+impl fmt::Display for LikePattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "like '{}'", self.pattern)?;
+        match &self.escape {
+            Some(escape) => write!(f, " escape '{}'", escape),
+            None => Ok(())
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ShowCreateType {
+    Table,
+    View,
+    MaterializedView
+}
+
+impl fmt::Display for ShowCreateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShowCreateType::Table => write!(f, "table"),
+            ShowCreateType::View => write!(f, "view"),
+            ShowCreateType::MaterializedView => write!(f, "materialized view")
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExplainOption {
+    Type(ExplainType),
+    Format(ExplainFormat)
+}
+
+impl fmt::Display for ExplainOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExplainOption::Type(explain_type) => write!(f, "type {}", explain_type),
+            ExplainOption::Format(format) => write!(f, "format {}", format)
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExplainType {
+    Logical,
+    Distributed,
+    Validate,
+    Io
+}
+
+impl fmt::Display for ExplainType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExplainType::Logical => write!(f, "logical"),
+            ExplainType::Distributed => write!(f, "distributed"),
+            ExplainType::Validate => write!(f, "validate"),
+            ExplainType::Io => write!(f, "io")
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExplainFormat {
+    Text,
+    Graphviz,
+    Json
+}
+
+impl fmt::Display for ExplainFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExplainFormat::Text => write!(f, "text"),
+            ExplainFormat::Graphviz => write!(f, "graphviz"),
+            ExplainFormat::Json => write!(f, "json")
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TransactionMode {
+    Isolation(IsolationLevel),
+    ReadOnly,
+    ReadWrite
+}
+
+impl fmt::Display for TransactionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TransactionMode::Isolation(level) => write!(f, "isolation level {}", level),
+            TransactionMode::ReadOnly => write!(f, "read only"),
+            TransactionMode::ReadWrite => write!(f, "read write")
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable
+}
+
+impl fmt::Display for IsolationLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IsolationLevel::ReadUncommitted => write!(f, "read uncommitted"),
+            IsolationLevel::ReadCommitted => write!(f, "read committed"),
+            IsolationLevel::RepeatableRead => write!(f, "repeatable read"),
+            IsolationLevel::Serializable => write!(f, "serializable")
+        }
+    }
+}
+
+// column = value in UPDATE ... SET and MERGE ... THEN UPDATE SET
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpdateAssignment {
+    pub name: String,
+    pub value: Expression
+}
+
+impl fmt::Display for UpdateAssignment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} = {}", self.name, self.value)
+    }
+}
+
+// WHEN [NOT] MATCHED [AND condition] THEN ... clause of a MERGE
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MergeCase {
+    Update {
+        condition: Option<Expression>,
+        assignments: Vec<UpdateAssignment>
+    },
+    Delete {
+        condition: Option<Expression>
+    },
+    Insert {
+        condition: Option<Expression>,
+        columns: Option<Vec<ColumnName>>,
+        values: Vec<Expression>
+    }
+}
+
+impl fmt::Display for MergeCase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let condition = match self {
+            MergeCase::Update { condition, .. } | MergeCase::Delete { condition } => {
+                write!(f, "when matched")?;
+                condition
+            }
+            MergeCase::Insert { condition, .. } => {
+                write!(f, "when not matched")?;
+                condition
+            }
+        };
+        if let Some(condition) = condition {
+            write!(f, " and {}", condition)?;
+        }
+        match self {
+            MergeCase::Update { assignments, .. } => write!(f, " then update set {}", join(assignments, ", ")),
+            MergeCase::Delete { .. } => write!(f, " then delete"),
+            MergeCase::Insert { columns, values, .. } => {
+                write!(f, " then insert")?;
+                write_column_aliases(f, columns)?;
+                write!(f, " values ({})", join(values, ", "))
+            }
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum TableElement {
-    ColumnDefinition(String, Box<Type>)
+    ColumnDefinition(ColumnDefinition),
+    Like {
+        table_name: QualifiedName,
+        properties: Option<LikeProperties>
+    },
+    Constraint(TableConstraint)
+}
+
+impl fmt::Display for TableElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TableElement::ColumnDefinition(column) => write!(f, "{}", column),
+            TableElement::Like { table_name, properties } => {
+                write!(f, "like {}", table_name)?;
+                match properties {
+                    Some(properties) => write!(f, " {}", properties),
+                    None => Ok(())
+                }
+            },
+            TableElement::Constraint(constraint) => write!(f, "{}", constraint)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnDefinition {
+    pub name: String,
+    pub column_type: Type,
+    pub not_null: bool,
+    pub default: Option<Expression>,
+    pub comment: Option<String>,
+    pub properties: Vec<Property>
+}
+
+impl fmt::Display for ColumnDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.column_type)?;
+        if self.not_null {
+            write!(f, " not null")?;
+        }
+        if let Some(default) = &self.default {
+            write!(f, " default ")?;
+            write_predicate_operand(f, default)?;
+        }
+        write_comment(f, &self.comment)?;
+        write_properties(f, &self.properties)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Property {
+    pub name: String,
+    pub value: Expression
+}
+
+impl fmt::Display for Property {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} = {}", self.name, self.value)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LikeProperties {
+    Including,
+    Excluding
+}
+
+impl fmt::Display for LikeProperties {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LikeProperties::Including => write!(f, "including properties"),
+            LikeProperties::Excluding => write!(f, "excluding properties")
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TableConstraint {
+    PrimaryKey(Vec<ColumnName>),
+    Unique(Vec<ColumnName>),
+    Check(Expression)
+}
+
+impl fmt::Display for TableConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TableConstraint::PrimaryKey(columns) => write!(f, "primary key ({})", join(columns, ", ")),
+            TableConstraint::Unique(columns) => write!(f, "unique ({})", join(columns, ", ")),
+            TableConstraint::Check(condition) => write!(f, "check ({})", condition)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RowField {
+    pub name: String,
+    pub field_type: Type
+}
+
+impl fmt::Display for RowField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.field_type)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
 pub enum Type {
     Array(Box<Type>),
     Map(Box<Type>, Box<Type>),
-    Row(Vec<TableElement>),
+    Row(Vec<RowField>),
+    // start field, optional end field
+    Interval(IntervalField, Option<IntervalField>),
     TIME_WITH_TIME_ZONE(Option<Vec<TypeParameter>>),
     TIMESTAMP_WITH_TIME_ZONE(Option<Vec<TypeParameter>>),
     DOUBLE_PRECISION(Option<Vec<TypeParameter>>),
     User_Defined(String, Option<Vec<TypeParameter>>)
 }
 
+fn write_type_parameters(f: &mut fmt::Formatter, type_params: &Option<Vec<TypeParameter>>) -> fmt::Result {
+    match type_params {
+        Some(type_params) => write!(f, "({})", join(type_params, ", ")),
+        None => Ok(())
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Type::Array(element_type) => write!(f, "array({})", element_type),
+            Type::Map(key_type, value_type) => write!(f, "map({}, {})", key_type, value_type),
+            Type::Row(fields) => write!(f, "row({})", join(fields, ", ")),
+            Type::Interval(from, Some(to)) => write!(f, "interval {} to {}", from, to),
+            Type::Interval(from, None) => write!(f, "interval {}", from),
+            Type::TIME_WITH_TIME_ZONE(type_params) => {
+                write!(f, "time with time zone")?;
+                write_type_parameters(f, type_params)
+            },
+            Type::TIMESTAMP_WITH_TIME_ZONE(type_params) => {
+                write!(f, "timestamp with time zone")?;
+                write_type_parameters(f, type_params)
+            },
+            Type::DOUBLE_PRECISION(type_params) => {
+                write!(f, "double precision")?;
+                write_type_parameters(f, type_params)
+            },
+            Type::User_Defined(name, type_params) => {
+                write!(f, "{}", name)?;
+                write_type_parameters(f, type_params)
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum TypeParameter {
     IntegerTypeParam(String),
     TypeParam(Type)
 }
 
+impl fmt::Display for TypeParameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TypeParameter::IntegerTypeParam(value) => write!(f, "{}", value),
+            TypeParameter::TypeParam(param_type) => write!(f, "{}", param_type)
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IntervalField {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second
+}
+
+impl fmt::Display for IntervalField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            IntervalField::Year => "year",
+            IntervalField::Month => "month",
+            IntervalField::Day => "day",
+            IntervalField::Hour => "hour",
+            IntervalField::Minute => "minute",
+            IntervalField::Second => "second"
+        };
+        write!(f, "{}", s)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum DropProp {
     CASCADE,
     RESTRICT
 }
 
+impl fmt::Display for DropProp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DropProp::CASCADE => write!(f, "cascade"),
+            DropProp::RESTRICT => write!(f, "restrict")
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct QualifiedName {
     pub name: Vec<String>
 }
 
+impl fmt::Display for QualifiedName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", join(&self.name, "."))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Query {
     pub with: Option<With>,
@@ -94,9 +1177,26 @@ pub struct Query {
 pub struct QueryBody {
     pub query_term: QueryTerm,
     pub order_by: Option<Vec<SortItem>>,
+    pub offset: Option<Offset>,
     pub limit: Option<Limit>
 }
 
+impl fmt::Display for QueryBody {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.query_term)?;
+        if let Some(order_by) = &self.order_by {
+            write!(f, " order by {}", join(order_by, ", "))?;
+        }
+        if let Some(offset) = &self.offset {
+            write!(f, " {}", offset)?;
+        }
+        if let Some(limit) = &self.limit {
+            write!(f, " {}", limit)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SortItem {
     pub expression: Expression,
@@ -104,24 +1204,65 @@ pub struct SortItem {
     pub null_order: Option<NullOrder>
 }
 
+impl fmt::Display for SortItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.expression)?;
+        if let Some(sort_order) = self.sort_order {
+            write!(f, " {}", sort_order)?;
+        }
+        if let Some(null_order) = self.null_order {
+            write!(f, " {}", null_order)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum SortOrder {
     Asc,
     Desc,
 }
 
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SortOrder::Asc => write!(f, "asc"),
+            SortOrder::Desc => write!(f, "desc")
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum NullOrder {
     First,
     Last,
 }
 
+impl fmt::Display for NullOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NullOrder::First => write!(f, "first"),
+            NullOrder::Last => write!(f, "last")
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct With {
     pub recursive: bool,
     pub body: Vec<NamedQuery>,
 }
 
+impl fmt::Display for With {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "with ")?;
+        if self.recursive {
+            write!(f, "recursive ")?;
+        }
+        write!(f, "{}", join(&self.body, ", "))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct NamedQuery {
     pub tbl_name: String,
@@ -129,36 +1270,356 @@ pub struct NamedQuery {
     pub body: Box<Statement>
 }
 
+impl fmt::Display for NamedQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.tbl_name)?;
+        write_column_aliases(f, &self.columns)?;
+        write!(f, " as ({})", self.body)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Select {
     pub distinctness: Option<Distinctness>,
     pub projection: Vec<SelectItem>,
-    pub from: String,
+    pub from: Option<Relation>,
     pub filter: Option<Expression>,
+    pub group_by: Option<GroupBy>,
+    pub having: Option<Expression>,
+    pub windows: Option<Vec<WindowDefinition>>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Limit {
-    pub expr: Expression,
-    pub offset: Option<Expression>, /* TODO distinction between LIMIT offset, count and LIMIT count
-                               * OFFSET offset */
+pub struct GroupBy {
+    pub distinct: bool,
+    pub elements: Vec<GroupingElement>
 }
 
-impl fmt::Display for Select {
+impl fmt::Display for GroupBy {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let distinctness = match self.distinctness {
-            Some(d) => format!("{}", d),
-            _ => String::from("")
+        if self.distinct {
+            write!(f, "distinct ")?;
+        }
+        write!(f, "{}", join(&self.elements, ", "))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GroupingElement {
+    // a single expression or a parenthesized grouping set
+    Simple(Vec<Expression>),
+    Rollup(Vec<Expression>),
+    Cube(Vec<Expression>),
+    GroupingSets(Vec<Vec<Expression>>)
+}
+
+impl GroupingElement {
+    // CUBE and ROLLUP are non-reserved, so `cube (a, b)` is first read as a call to a function named
+    // `cube`. `text` is the source from the start of the set, which keeps a quoted `"cube"(a)` a call
+    pub fn from_set(set: Vec<Expression>, text: &str) -> GroupingElement {
+        let word = text.split(|c: char| !c.is_alphanumeric() && c != '_').next().unwrap_or_default();
+        let arguments = match set.as_slice() {
+            [Expression::FunctionCall(FunctionCall {
+                name, distinct: false, all_rows: false, arguments, order_by: None, filter: None, window: None
+            })] if name.name.len() == 1 => arguments.iter()
+                .map(|argument| match argument {
+                    FunctionArgument::Positional(expression) => Some(expression.clone()),
+                    FunctionArgument::Named(..) => None
+                })
+                .collect::<Option<Vec<_>>>(),
+            _ => None
         };
-        let res = match &self.filter {
-            Some(filter) => write!(f, "select {} {} \n from {} where {}",
-                                   distinctness, join(&self.projection, ","),
-                                   self.from, filter),
-            _ => write!(f, "select {} {} \n from {}",
-                        distinctness, join(&self.projection, ","),
-                        self.from)
+        match arguments {
+            Some(arguments) if word.eq_ignore_ascii_case("cube") => GroupingElement::Cube(arguments),
+            Some(arguments) if word.eq_ignore_ascii_case("rollup") => GroupingElement::Rollup(arguments),
+            _ => GroupingElement::Simple(set)
+        }
+    }
+}
+
+fn write_grouping_set(f: &mut fmt::Formatter, expressions: &[Expression]) -> fmt::Result {
+    match expressions {
+        [expression] => write!(f, "{}", expression),
+        _ => write!(f, "({})", join(expressions, ", "))
+    }
+}
+
+impl fmt::Display for GroupingElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GroupingElement::Simple(expressions) => write_grouping_set(f, expressions),
+            GroupingElement::Rollup(expressions) => write!(f, "rollup ({})", join(expressions, ", ")),
+            GroupingElement::Cube(expressions) => write!(f, "cube ({})", join(expressions, ", ")),
+            GroupingElement::GroupingSets(sets) => {
+                write!(f, "grouping sets (")?;
+                for (i, set) in sets.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write_grouping_set(f, set)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Relation {
+    Table(QualifiedName),
+    // (query)
+    Subquery(Box<Statement>),
+    // relation [AS] alias [(columns)]
+    Aliased {
+        relation: Box<Relation>,
+        alias: String,
+        columns: Option<Vec<ColumnName>>
+    },
+    // tables listed with commas are joined with `JoinType::Implicit` and no criteria,
+    // CROSS joins have no criteria either
+    Join {
+        join_type: JoinType,
+        left: Box<Relation>,
+        right: Box<Relation>,
+        criteria: Option<JoinCriteria>
+    },
+    // UNNEST(expressions) [WITH ORDINALITY]
+    Unnest {
+        expressions: Vec<Expression>,
+        with_ordinality: bool
+    },
+    // LATERAL (query)
+    Lateral(Box<Statement>),
+    // relation TABLESAMPLE sample_type (percentage)
+    Sampled {
+        relation: Box<Relation>,
+        sample_type: SampleType,
+        percentage: Box<Expression>
+    }
+}
+
+impl Relation {
+    fn is_join(&self) -> bool {
+        matches!(self, Relation::Join { .. })
+    }
+
+    fn is_implicit_join(&self) -> bool {
+        matches!(self, Relation::Join { join_type: JoinType::Implicit, .. })
+    }
+
+    fn is_sampled(&self) -> bool {
+        matches!(self, Relation::Sampled { .. })
+    }
+}
+
+// Writes `relation`, wrapping it in parentheses when `parenthesize` holds
+fn write_relation(f: &mut fmt::Formatter, relation: &Relation, parenthesize: bool) -> fmt::Result {
+    if parenthesize {
+        write!(f, "({})", relation)
+    } else {
+        write!(f, "{}", relation)
+    }
+}
+
+impl fmt::Display for Relation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Relation::Table(name) => write!(f, "{}", name),
+            Relation::Subquery(query) => write!(f, "({})", query),
+            Relation::Aliased {
+                relation, alias, columns
+            } => {
+                write_relation(f, relation, relation.is_join() || relation.is_sampled())?;
+                write!(f, " as {}", alias)?;
+                write_column_aliases(f, columns)
+            },
+            // Comma separated relations bind looser than any other join
+            Relation::Join {
+                join_type: JoinType::Implicit, left, right, ..
+            } => {
+                write!(f, "{}, ", left)?;
+                write_relation(f, right, right.is_implicit_join())
+            },
+            Relation::Join {
+                join_type, left, right, criteria
+            } => {
+                write_relation(f, left, left.is_implicit_join())?;
+                if let Some(JoinCriteria::Natural) = criteria {
+                    write!(f, " natural")?;
+                }
+                write!(f, " {} join ", join_type)?;
+                write_relation(f, right, right.is_join())?;
+                match criteria {
+                    Some(JoinCriteria::On(expression)) => write!(f, " on {}", expression),
+                    Some(JoinCriteria::Using(columns)) => write!(f, " using ({})", join(columns, ", ")),
+                    _ => Ok(())
+                }
+            },
+            Relation::Unnest {
+                expressions, with_ordinality
+            } => {
+                write!(f, "unnest({})", join(expressions, ", "))?;
+                if *with_ordinality {
+                    write!(f, " with ordinality")?;
+                }
+                Ok(())
+            },
+            Relation::Lateral(query) => write!(f, "lateral ({})", query),
+            Relation::Sampled {
+                relation, sample_type, percentage
+            } => {
+                write_relation(f, relation, relation.is_join() || relation.is_sampled())?;
+                write!(f, " tablesample {} ({})", sample_type, percentage)
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum JoinType {
+    Implicit,
+    Cross,
+    Inner,
+    Left,
+    Right,
+    Full
+}
+
+impl fmt::Display for JoinType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            JoinType::Implicit => ",",
+            JoinType::Cross => "cross",
+            JoinType::Inner => "inner",
+            JoinType::Left => "left",
+            JoinType::Right => "right",
+            JoinType::Full => "full"
         };
-        return res
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SampleType {
+    Bernoulli,
+    System
+}
+
+impl fmt::Display for SampleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SampleType::Bernoulli => write!(f, "bernoulli"),
+            SampleType::System => write!(f, "system")
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JoinCriteria {
+    On(Expression),
+    Using(Vec<ColumnName>),
+    Natural
+}
+
+// OFFSET n [ROW | ROWS], written before LIMIT or FETCH
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Offset {
+    pub row_count: Expression,
+    pub rows: Option<RowKeyword>
+}
+
+impl fmt::Display for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "offset {}", self.row_count)?;
+        match self.rows {
+            Some(rows) => write!(f, " {}", rows),
+            None => Ok(())
+        }
+    }
+}
+
+// Each variant keeps the syntax it was written in
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Limit {
+    // LIMIT count
+    Count(Expression),
+    // LIMIT ALL
+    All,
+    // LIMIT count OFFSET offset
+    CountOffset {
+        count: Expression,
+        offset: Expression
+    },
+    // LIMIT offset, count
+    OffsetCount {
+        offset: Expression,
+        count: Expression
+    },
+    // FETCH {FIRST | NEXT} [count] {ROW | ROWS} {ONLY | WITH TIES}
+    Fetch {
+        next: bool,
+        count: Option<Expression>,
+        rows: RowKeyword,
+        with_ties: bool
+    }
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Limit::Count(count) => write!(f, "limit {}", count),
+            Limit::All => write!(f, "limit all"),
+            Limit::CountOffset { count, offset } => write!(f, "limit {} offset {}", count, offset),
+            Limit::OffsetCount { offset, count } => write!(f, "limit {}, {}", offset, count),
+            Limit::Fetch { next, count, rows, with_ties } => {
+                write!(f, "fetch {}", if *next { "next" } else { "first" })?;
+                if let Some(count) = count {
+                    write!(f, " {}", count)?;
+                }
+                write!(f, " {} {}", rows, if *with_ties { "with ties" } else { "only" })
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RowKeyword {
+    Row,
+    Rows
+}
+
+impl fmt::Display for RowKeyword {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RowKeyword::Row => write!(f, "row"),
+            RowKeyword::Rows => write!(f, "rows")
+        }
+    }
+}
+
+impl fmt::Display for Select {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "select ")?;
+        if let Some(distinctness) = self.distinctness {
+            write!(f, "{} ", distinctness)?;
+        }
+        write!(f, "{}", join(&self.projection, ", "))?;
+        if let Some(from) = &self.from {
+            write!(f, " from {}", from)?;
+        }
+        if let Some(filter) = &self.filter {
+            write!(f, " where {}", filter)?;
+        }
+        if let Some(group_by) = &self.group_by {
+            write!(f, " group by {}", group_by)?;
+        }
+        if let Some(having) = &self.having {
+            write!(f, " having {}", having)?;
+        }
+        if let Some(windows) = &self.windows {
+            write!(f, " window {}", join(windows, ", "))?;
+        }
+        Ok(())
     }
 }
 
@@ -171,20 +1632,88 @@ impl NodeTrait for Select {
 
     fn get_children(&self) -> Vec<Node> {
         // FixME
-        return vec!()
+        vec!()
     }
 }
 
+// Set operations form a binary tree, with INTERSECT binding tighter than UNION and EXCEPT
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct QueryTerm {
-    pub select: Select,
-    pub other: Option<SetQueryTerm>
+#[allow(clippy::large_enum_variant)]
+pub enum QueryTerm {
+    Primary(QueryPrimary),
+    SetOperation {
+        left: Box<QueryTerm>,
+        operator: SetOperator,
+        // a missing quantifier means DISTINCT
+        distinct: bool,
+        right: Box<QueryTerm>
+    }
+}
+
+impl QueryTerm {
+    fn precedence(&self) -> u8 {
+        match self {
+            QueryTerm::Primary(_) => 3,
+            QueryTerm::SetOperation { operator: SetOperator::Intersect, .. } => 2,
+            QueryTerm::SetOperation { .. } => 1
+        }
+    }
+}
+
+// Operands are parenthesized where the tree does not follow the grammar's precedence and
+// left associativity
+impl fmt::Display for QueryTerm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QueryTerm::Primary(primary) => write!(f, "{}", primary),
+            QueryTerm::SetOperation { left, operator, distinct, right } => {
+                let precedence = self.precedence();
+                if left.precedence() < precedence {
+                    write!(f, "({})", left)?;
+                } else {
+                    write!(f, "{}", left)?;
+                }
+                write!(f, " {}", operator)?;
+                if !distinct {
+                    write!(f, " all")?;
+                }
+                if right.precedence() <= precedence {
+                    write!(f, " ({})", right)
+                } else {
+                    write!(f, " {}", right)
+                }
+            }
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct SetQueryTerm {
-    pub operator: SetOperator,
-    pub query: Box<QueryTerm>
+#[allow(clippy::large_enum_variant)]
+pub enum QueryPrimary {
+    Select(Select),
+    // VALUES row, ... with each row holding one expression per column
+    Values(Vec<Vec<Expression>>),
+    // a parenthesized query, which may carry its own ORDER BY and LIMIT
+    Subquery(Box<QueryBody>)
+}
+
+impl fmt::Display for QueryPrimary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QueryPrimary::Select(select) => write!(f, "{}", select),
+            QueryPrimary::Values(rows) => {
+                write!(f, "values ")?;
+                for (i, row) in rows.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "({})", join(row, ", "))?;
+                }
+                Ok(())
+            }
+            QueryPrimary::Subquery(body) => write!(f, "({})", body)
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -194,6 +1723,16 @@ pub enum SetOperator {
     Except
 }
 
+impl fmt::Display for SetOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SetOperator::Union => write!(f, "union"),
+            SetOperator::Intersect => write!(f, "intersect"),
+            SetOperator::Except => write!(f, "except")
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Distinctness {
     Distinct,
@@ -210,18 +1749,27 @@ impl std::fmt::Display for Distinctness {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct SelectItem {
-    pub expression: Expression,
-    pub alias: Option<AliasName>
+pub enum SelectItem {
+    SingleColumn {
+        expression: Expression,
+        alias: Option<AliasName>
+    },
+    // `*`, or `prefix.*` for the columns of one relation
+    AllColumns(Option<QualifiedName>)
 }
 
 impl fmt::Display for SelectItem {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let res = match &self.alias {
-            Some(a) => write!(f, "{} as {}", self.expression, a),
-            _ => write!(f, "{}", self.expression)
-        };
-        res
+        match self {
+            SelectItem::SingleColumn {
+                expression, alias: Some(alias)
+            } => write!(f, "{} as {}", expression, alias),
+            SelectItem::SingleColumn {
+                expression, alias: None
+            } => write!(f, "{}", expression),
+            SelectItem::AllColumns(Some(prefix)) => write!(f, "{}.*", prefix),
+            SelectItem::AllColumns(None) => write!(f, "*")
+        }
     }
 }
 
@@ -234,7 +1782,7 @@ impl NodeTrait for SelectItem {
 
     // FixMe
     fn get_children(&self) -> Vec<Node> {
-        return vec!()
+        vec!()
     }
 }
 
@@ -253,7 +1801,7 @@ impl NodeTrait for AliasName {
 
     fn get_children(&self) -> Vec<Node> {
         // FixMe
-        return vec!()
+        vec!()
     }
 }
 
@@ -272,7 +1820,7 @@ impl NodeTrait for ColumnName {
 
     fn get_children(&self) -> Vec<Node> {
         // FixMe
-        return vec!()
+        vec!()
     }
 }
 
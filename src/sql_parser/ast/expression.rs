@@ -1,6 +1,10 @@
 use std::fmt;
 use std::fmt::Debug;
+use std::collections::HashMap;
 use crate::sql_parser::ast::node::{Node, NodeTrait};
+use crate::sql_parser::ast::basic_ast::{IntervalField, QualifiedName, SortItem, Statement, Type};
+use crate::sql_parser::token::ErrorCode;
+use itertools::join;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Expression {
@@ -8,7 +12,149 @@ pub enum Expression {
     Identifier {
         name: String
     },
-    Literal(Literal)
+    Literal(Literal),
+    FunctionCall(FunctionCall),
+    // CAST, or TRY_CAST when `safe` is set
+    Cast {
+        expression: Box<Expression>,
+        data_type: Type,
+        safe: bool
+    },
+    // CASE operand WHEN value THEN result ... [ELSE default] END
+    SimpleCase {
+        operand: Box<Expression>,
+        when_clauses: Vec<WhenClause>,
+        default: Option<Box<Expression>>
+    },
+    // CASE WHEN condition THEN result ... [ELSE default] END
+    SearchedCase {
+        when_clauses: Vec<WhenClause>,
+        default: Option<Box<Expression>>
+    },
+    // at least two operands
+    Coalesce(Vec<Expression>),
+    NullIf(Box<Expression>, Box<Expression>),
+    If {
+        condition: Box<Expression>,
+        true_value: Box<Expression>,
+        false_value: Option<Box<Expression>>
+    },
+    // ARRAY[elements]
+    Array(Vec<Expression>),
+    // MAP(key array, value array)
+    Map {
+        keys: Box<Expression>,
+        values: Box<Expression>
+    },
+    // ROW(fields)
+    Row(Vec<Expression>),
+    // base[index]
+    Subscript {
+        base: Box<Expression>,
+        index: Box<Expression>
+    },
+    // base.field
+    Dereference {
+        base: Box<Expression>,
+        field: String
+    },
+    // value [NOT] BETWEEN min AND max
+    Between {
+        value: Box<Expression>,
+        min: Box<Expression>,
+        max: Box<Expression>,
+        not: bool
+    },
+    // value [NOT] IN (list)
+    InList {
+        value: Box<Expression>,
+        list: Vec<Expression>,
+        not: bool
+    },
+    // value [NOT] IN (subquery)
+    InSubquery {
+        value: Box<Expression>,
+        subquery: Box<Statement>,
+        not: bool
+    },
+    // value [NOT] LIKE pattern [ESCAPE escape]
+    Like {
+        value: Box<Expression>,
+        pattern: Box<Expression>,
+        escape: Option<Box<Expression>>,
+        not: bool
+    },
+    // value IS [NOT] NULL
+    IsNull {
+        value: Box<Expression>,
+        not: bool
+    },
+    // lhs IS [NOT] DISTINCT FROM rhs
+    IsDistinctFrom {
+        lhs: Box<Expression>,
+        rhs: Box<Expression>,
+        not: bool
+    },
+    // EXISTS (subquery)
+    Exists(Box<Statement>),
+    // lhs operator ALL|ANY|SOME (subquery)
+    QuantifiedComparison {
+        lhs: Box<Expression>,
+        operator: BinaryOperator,
+        quantifier: ComparisonQuantifier,
+        subquery: Box<Statement>
+    },
+    // scalar (subquery)
+    Subquery(Box<Statement>),
+    // GROUPING(columns), tells which of the columns are aggregated in the current grouping set
+    Grouping(Vec<QualifiedName>),
+    // x -> body or (x, y) -> body
+    Lambda {
+        arguments: Vec<String>,
+        body: Box<Expression>
+    },
+    // use of a lambda argument inside the lambda body
+    LambdaArgument {
+        name: String
+    },
+    // `?`, `?1`, `:name`, `$name` or `@name` as written, `position` is the index of the bound value,
+    // see `ParameterPositions`
+    Parameter {
+        position: usize,
+        name: String
+    }
+}
+
+// Numbers parameters as they are read: `?N` takes position N - 1, a bare `?` the position after
+// the highest one so far and a name the position after the highest one the first time it is seen,
+// the same one afterwards. Numbered parameters cannot be mixed with the other two, as their
+// positions could then collide
+#[derive(Default)]
+pub struct ParameterPositions {
+    next: usize,
+    names: HashMap<String, usize>,
+    // whether the parameters read so far are numbered, unknown before the first one
+    numbered: Option<bool>
+}
+
+impl ParameterPositions {
+    // `BadVariableName` for `?0` as numbered parameters start at 1
+    pub fn position(&mut self, name: &str) -> Result<usize, ErrorCode> {
+        let numbered = name.starts_with('?');
+        if *self.numbered.get_or_insert(numbered) != numbered {
+            return Err(ErrorCode::MixedParameters);
+        }
+        let position = if name.is_empty() {
+            self.next
+        } else if let Some(number) = name.strip_prefix('?') {
+            number.parse::<usize>().ok().and_then(|number| number.checked_sub(1)).ok_or(ErrorCode::BadVariableName)?
+        } else {
+            let next = self.next;
+            *self.names.entry(name.to_string()).or_insert(next)
+        };
+        self.next = self.next.max(position + 1);
+        Ok(position)
+    }
 }
 
 impl From<BooleanExpression> for Expression {
@@ -17,6 +163,248 @@ impl From<BooleanExpression> for Expression {
     }
 }
 
+// `a.b.c` becomes the identifier `a` dereferenced twice
+impl From<QualifiedName> for Expression {
+    fn from(qualified_name: QualifiedName) -> Expression {
+        let mut parts = qualified_name.name.into_iter();
+        let first = Expression::Identifier {
+            name: parts.next().expect("qualified name without parts")
+        };
+        parts.fold(first, |base, field| Expression::Dereference {
+            base: Box::new(base), field
+        })
+    }
+}
+
+impl Expression {
+    // Builds a lambda, identifiers in `body` naming one of the arguments become argument references
+    pub fn lambda(arguments: Vec<String>, body: Expression) -> Expression {
+        let body = resolve_lambda_arguments(body, &arguments);
+        Expression::Lambda { arguments, body: Box::new(body) }
+    }
+
+    // Rebuilds the expression with `f` applied to each of its direct subexpressions.
+    // Queries nested in the expression are left untouched.
+    pub fn map_children<F: FnMut(Expression) -> Expression>(self, f: &mut F) -> Expression {
+        match self {
+            Expression::BooleanExpr(BooleanExpression::BinaryExpression {
+                lhs, operator, rhs
+            }) => BooleanExpression::BinaryExpression {
+                lhs: map_box(lhs, f), operator, rhs: map_box(rhs, f)
+            }.into(),
+            Expression::BooleanExpr(BooleanExpression::UnaryExpression {
+                operator, operand
+            }) => BooleanExpression::UnaryExpression {
+                operator, operand: map_box(operand, f)
+            }.into(),
+            Expression::FunctionCall(function_call) => Expression::FunctionCall(FunctionCall {
+                name: function_call.name,
+                distinct: function_call.distinct,
+                all_rows: function_call.all_rows,
+                arguments: function_call.arguments.into_iter().map(|argument| match argument {
+                    FunctionArgument::Positional(value) => FunctionArgument::Positional(f(value)),
+                    FunctionArgument::Named(name, value) => FunctionArgument::Named(name, f(value))
+                }).collect(),
+                order_by: function_call.order_by.map(|order_by| map_sort_items(order_by, f)),
+                filter: function_call.filter.map(|filter| map_box(filter, f)),
+                window: function_call.window.map(|window| match window {
+                    Window::Specification(specification) => Window::Specification(map_window(specification, f)),
+                    reference => reference
+                })
+            }),
+            Expression::Cast {
+                expression, data_type, safe
+            } => Expression::Cast {
+                expression: map_box(expression, f), data_type, safe
+            },
+            Expression::SimpleCase {
+                operand, when_clauses, default
+            } => Expression::SimpleCase {
+                operand: map_box(operand, f),
+                when_clauses: map_when_clauses(when_clauses, f),
+                default: default.map(|default| map_box(default, f))
+            },
+            Expression::SearchedCase {
+                when_clauses, default
+            } => Expression::SearchedCase {
+                when_clauses: map_when_clauses(when_clauses, f),
+                default: default.map(|default| map_box(default, f))
+            },
+            Expression::Coalesce(operands) => Expression::Coalesce(map_vec(operands, f)),
+            Expression::NullIf(first, second) => Expression::NullIf(map_box(first, f), map_box(second, f)),
+            Expression::If {
+                condition, true_value, false_value
+            } => Expression::If {
+                condition: map_box(condition, f),
+                true_value: map_box(true_value, f),
+                false_value: false_value.map(|false_value| map_box(false_value, f))
+            },
+            Expression::Array(elements) => Expression::Array(map_vec(elements, f)),
+            Expression::Map {
+                keys, values
+            } => Expression::Map {
+                keys: map_box(keys, f), values: map_box(values, f)
+            },
+            Expression::Row(fields) => Expression::Row(map_vec(fields, f)),
+            Expression::Subscript {
+                base, index
+            } => Expression::Subscript {
+                base: map_box(base, f), index: map_box(index, f)
+            },
+            Expression::Dereference {
+                base, field
+            } => Expression::Dereference {
+                base: map_box(base, f), field
+            },
+            Expression::Between {
+                value, min, max, not
+            } => Expression::Between {
+                value: map_box(value, f), min: map_box(min, f), max: map_box(max, f), not
+            },
+            Expression::InList {
+                value, list, not
+            } => Expression::InList {
+                value: map_box(value, f), list: map_vec(list, f), not
+            },
+            Expression::InSubquery {
+                value, subquery, not
+            } => Expression::InSubquery {
+                value: map_box(value, f), subquery, not
+            },
+            Expression::Like {
+                value, pattern, escape, not
+            } => Expression::Like {
+                value: map_box(value, f),
+                pattern: map_box(pattern, f),
+                escape: escape.map(|escape| map_box(escape, f)),
+                not
+            },
+            Expression::IsNull {
+                value, not
+            } => Expression::IsNull {
+                value: map_box(value, f), not
+            },
+            Expression::IsDistinctFrom {
+                lhs, rhs, not
+            } => Expression::IsDistinctFrom {
+                lhs: map_box(lhs, f), rhs: map_box(rhs, f), not
+            },
+            Expression::QuantifiedComparison {
+                lhs, operator, quantifier, subquery
+            } => Expression::QuantifiedComparison {
+                lhs: map_box(lhs, f), operator, quantifier, subquery
+            },
+            Expression::Lambda {
+                arguments, body
+            } => Expression::Lambda {
+                arguments, body: map_box(body, f)
+            },
+            Expression::Identifier { .. }
+            | Expression::Literal(_)
+            | Expression::Exists(_)
+            | Expression::Subquery(_)
+            | Expression::Grouping(_)
+            | Expression::LambdaArgument { .. }
+            | Expression::Parameter { .. } => self
+        }
+    }
+
+    // Rebuilds the expression with `f` applied to each query nested directly in it
+    pub fn map_subqueries<F: FnMut(Statement) -> Statement>(self, f: &mut F) -> Expression {
+        match self {
+            Expression::InSubquery {
+                value, subquery, not
+            } => Expression::InSubquery {
+                value, subquery: map_statement(subquery, f), not
+            },
+            Expression::Exists(subquery) => Expression::Exists(map_statement(subquery, f)),
+            Expression::QuantifiedComparison {
+                lhs, operator, quantifier, subquery
+            } => Expression::QuantifiedComparison {
+                lhs, operator, quantifier, subquery: map_statement(subquery, f)
+            },
+            Expression::Subquery(subquery) => Expression::Subquery(map_statement(subquery, f)),
+            expression => expression
+        }
+    }
+}
+
+fn map_statement<F: FnMut(Statement) -> Statement>(mut statement: Box<Statement>, f: &mut F) -> Box<Statement> {
+    *statement = f(*statement);
+    statement
+}
+
+fn map_box<F: FnMut(Expression) -> Expression>(mut expression: Box<Expression>, f: &mut F) -> Box<Expression> {
+    *expression = f(*expression);
+    expression
+}
+
+fn map_vec<F: FnMut(Expression) -> Expression>(expressions: Vec<Expression>, f: &mut F) -> Vec<Expression> {
+    expressions.into_iter().map(f).collect()
+}
+
+pub(crate) fn map_sort_items<F: FnMut(Expression) -> Expression>(sort_items: Vec<SortItem>, f: &mut F) -> Vec<SortItem> {
+    sort_items.into_iter().map(|sort_item| SortItem {
+        expression: f(sort_item.expression), ..sort_item
+    }).collect()
+}
+
+fn map_when_clauses<F: FnMut(Expression) -> Expression>(when_clauses: Vec<WhenClause>, f: &mut F) -> Vec<WhenClause> {
+    when_clauses.into_iter().map(|when_clause| WhenClause {
+        operand: f(when_clause.operand), result: f(when_clause.result)
+    }).collect()
+}
+
+pub(crate) fn map_window<F: FnMut(Expression) -> Expression>(specification: WindowSpecification, f: &mut F) -> WindowSpecification {
+    let partition_by = map_vec(specification.partition_by, f);
+    let order_by = specification.order_by.map(|order_by| map_sort_items(order_by, f));
+    let mut map_bound = |bound| match bound {
+        FrameBound::Preceding(offset) => FrameBound::Preceding(map_box(offset, f)),
+        FrameBound::Following(offset) => FrameBound::Following(map_box(offset, f)),
+        bound => bound
+    };
+    WindowSpecification {
+        existing_window: specification.existing_window,
+        partition_by,
+        order_by,
+        frame: specification.frame.map(|frame| WindowFrame {
+            frame_type: frame.frame_type,
+            start: map_bound(frame.start),
+            end: frame.end.map(map_bound)
+        })
+    }
+}
+
+// Arguments of lambdas nested in `expression` were resolved when those were built,
+// so only the free identifiers are left and shadowing needs no special care
+fn resolve_lambda_arguments(expression: Expression, arguments: &[String]) -> Expression {
+    match expression {
+        Expression::Identifier { name } if arguments.iter().any(|argument| argument.eq_ignore_ascii_case(&name)) => {
+            Expression::LambdaArgument { name }
+        },
+        expression => expression.map_children(&mut |child| resolve_lambda_arguments(child, arguments))
+    }
+}
+
+// Whether `expression` can be written in front of `[index]` or `.field` without parentheses
+fn is_postfix_base(expression: &Expression) -> bool {
+    matches!(expression,
+        Expression::Identifier { .. }
+        | Expression::FunctionCall(_)
+        | Expression::Cast { .. }
+        | Expression::Coalesce(_)
+        | Expression::NullIf(..)
+        | Expression::If { .. }
+        | Expression::Array(_)
+        | Expression::Map { .. }
+        | Expression::Row(_)
+        | Expression::Subscript { .. }
+        | Expression::Dereference { .. }
+        | Expression::Subquery(_)
+        | Expression::LambdaArgument { .. }
+        | Expression::Parameter { .. })
+}
+
 impl fmt::Display for Expression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -24,24 +412,349 @@ impl fmt::Display for Expression {
             Expression::Identifier {
                 name
             } => write!(f, "{}", name),
-            _ => write!(f, "unsupported")
+            Expression::Literal(literal) => write!(f, "{}", literal),
+            Expression::FunctionCall(function_call) => write!(f, "{}", function_call),
+            Expression::Cast {
+                expression, data_type, safe
+            } => {
+                let function_name = if *safe { "try_cast" } else { "cast" };
+                write!(f, "{}({} as {})", function_name, expression, data_type)
+            },
+            Expression::SimpleCase {
+                operand, when_clauses, default
+            } => {
+                write!(f, "case {} {}", operand, join(when_clauses, " "))?;
+                write_case_default(f, default)
+            },
+            Expression::SearchedCase {
+                when_clauses, default
+            } => {
+                write!(f, "case {}", join(when_clauses, " "))?;
+                write_case_default(f, default)
+            },
+            Expression::Coalesce(operands) => write!(f, "coalesce({})", join(operands, ", ")),
+            Expression::NullIf(first, second) => write!(f, "nullif({}, {})", first, second),
+            Expression::If {
+                condition, true_value, false_value
+            } => match false_value {
+                Some(false_value) => write!(f, "if({}, {}, {})", condition, true_value, false_value),
+                None => write!(f, "if({}, {})", condition, true_value)
+            },
+            Expression::Array(elements) => write!(f, "array[{}]", join(elements, ", ")),
+            Expression::Map {
+                keys, values
+            } => write!(f, "map({}, {})", keys, values),
+            Expression::Row(fields) => write!(f, "row({})", join(fields, ", ")),
+            Expression::Subscript {
+                base, index
+            } => {
+                write_operand(f, base, !is_postfix_base(base))?;
+                write!(f, "[{}]", index)
+            },
+            Expression::Dereference {
+                base, field
+            } => {
+                write_operand(f, base, !is_postfix_base(base))?;
+                write!(f, ".{}", field)
+            },
+            Expression::Between {
+                value, min, max, not
+            } => {
+                write_predicate_operand(f, value)?;
+                write!(f, " {}between ", not_keyword(*not))?;
+                write_predicate_operand(f, min)?;
+                write!(f, " and ")?;
+                write_predicate_operand(f, max)
+            },
+            Expression::InList {
+                value, list, not
+            } => {
+                write_predicate_operand(f, value)?;
+                write!(f, " {}in ({})", not_keyword(*not), join(list, ", "))
+            },
+            Expression::InSubquery {
+                value, subquery, not
+            } => {
+                write_predicate_operand(f, value)?;
+                write!(f, " {}in ({})", not_keyword(*not), subquery)
+            },
+            Expression::Like {
+                value, pattern, escape, not
+            } => {
+                write_predicate_operand(f, value)?;
+                write!(f, " {}like ", not_keyword(*not))?;
+                write_predicate_operand(f, pattern)?;
+                if let Some(escape) = escape {
+                    write!(f, " escape ")?;
+                    write_predicate_operand(f, escape)?;
+                }
+                Ok(())
+            },
+            Expression::IsNull {
+                value, not
+            } => {
+                write_predicate_operand(f, value)?;
+                write!(f, " is {}null", not_keyword(*not))
+            },
+            Expression::IsDistinctFrom {
+                lhs, rhs, not
+            } => {
+                write_predicate_operand(f, lhs)?;
+                write!(f, " is {}distinct from ", not_keyword(*not))?;
+                write_predicate_operand(f, rhs)
+            },
+            Expression::Exists(subquery) => write!(f, "exists ({})", subquery),
+            Expression::QuantifiedComparison {
+                lhs, operator, quantifier, subquery
+            } => {
+                write_predicate_operand(f, lhs)?;
+                write!(f, " {} {} ({})", operator, quantifier, subquery)
+            },
+            Expression::Subquery(subquery) => write!(f, "({})", subquery),
+            Expression::Grouping(columns) => write!(f, "grouping({})", join(columns, ", ")),
+            Expression::Lambda {
+                arguments, body
+            } => match arguments.as_slice() {
+                [argument] => write!(f, "{} -> {}", argument, body),
+                _ => write!(f, "({}) -> {}", join(arguments, ", "), body)
+            },
+            Expression::LambdaArgument {
+                name
+            } => write!(f, "{}", name),
+            Expression::Parameter {
+                name, ..
+            } => write!(f, "{}", name),
         }
     }
 }
 
+fn not_keyword(not: bool) -> &'static str {
+    if not { "not " } else { "" }
+}
+
+// Operands of comparisons and predicates are parenthesized unless they bind tighter
+pub(crate) fn write_predicate_operand(f: &mut fmt::Formatter, expression: &Expression) -> fmt::Result {
+    write_operand(f, expression, precedence(expression) <= PREDICATE_PRECEDENCE)
+}
+
+fn write_case_default(f: &mut fmt::Formatter, default: &Option<Box<Expression>>) -> fmt::Result {
+    match default {
+        Some(default) => write!(f, " else {} end", default),
+        None => write!(f, " end")
+    }
+}
+
 impl NodeTrait for Expression {
 
     fn get_children(&self) -> Vec<Node> {
         match self {
             Expression::BooleanExpr(_) => vec!(),
             Expression::Identifier {
-                name
+                name: _
             } => vec!(),
             _ => vec!()
         }
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ComparisonQuantifier {
+    All,
+    Any,
+    Some
+}
+
+impl fmt::Display for ComparisonQuantifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ComparisonQuantifier::All => write!(f, "all"),
+            ComparisonQuantifier::Any => write!(f, "any"),
+            ComparisonQuantifier::Some => write!(f, "some")
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WhenClause {
+    pub operand: Expression,
+    pub result: Expression
+}
+
+impl fmt::Display for WhenClause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "when {} then {}", self.operand, self.result)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionCall {
+    pub name: QualifiedName,
+    pub distinct: bool,
+    // `count(*)` has no arguments and `all_rows` set, which tells it from `count()`
+    pub all_rows: bool,
+    pub arguments: Vec<FunctionArgument>,
+    pub order_by: Option<Vec<SortItem>>,
+    pub filter: Option<Box<Expression>>,
+    pub window: Option<Window>
+}
+
+impl fmt::Display for FunctionCall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}(", self.name)?;
+        if self.distinct {
+            write!(f, "distinct ")?;
+        }
+        if self.all_rows {
+            write!(f, "*")?;
+        }
+        write!(f, "{}", join(&self.arguments, ", "))?;
+        if let Some(order_by) = &self.order_by {
+            write!(f, " order by {}", join(order_by, ", "))?;
+        }
+        write!(f, ")")?;
+        if let Some(filter) = &self.filter {
+            write!(f, " filter (where {})", filter)?;
+        }
+        if let Some(window) = &self.window {
+            write!(f, " over {}", window)?;
+        }
+        Ok(())
+    }
+}
+
+// OVER name or OVER (specification)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Window {
+    Reference(String),
+    Specification(WindowSpecification)
+}
+
+impl fmt::Display for Window {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Window::Reference(name) => write!(f, "{}", name),
+            Window::Specification(specification) => write!(f, "({})", specification)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WindowSpecification {
+    // name of a window from the WINDOW clause this one extends
+    pub existing_window: Option<String>,
+    pub partition_by: Vec<Expression>,
+    pub order_by: Option<Vec<SortItem>>,
+    pub frame: Option<WindowFrame>
+}
+
+impl fmt::Display for WindowSpecification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = vec![];
+        if let Some(existing_window) = &self.existing_window {
+            parts.push(existing_window.clone());
+        }
+        if !self.partition_by.is_empty() {
+            parts.push(format!("partition by {}", join(&self.partition_by, ", ")));
+        }
+        if let Some(order_by) = &self.order_by {
+            parts.push(format!("order by {}", join(order_by, ", ")));
+        }
+        if let Some(frame) = &self.frame {
+            parts.push(frame.to_string());
+        }
+        write!(f, "{}", join(parts, " "))
+    }
+}
+
+// name AS (specification) in the WINDOW clause of a query
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WindowDefinition {
+    pub name: String,
+    pub window: WindowSpecification
+}
+
+impl fmt::Display for WindowDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} as ({})", self.name, self.window)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WindowFrame {
+    pub frame_type: FrameType,
+    pub start: FrameBound,
+    pub end: Option<FrameBound>
+}
+
+impl fmt::Display for WindowFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.end {
+            Some(end) => write!(f, "{} between {} and {}", self.frame_type, self.start, end),
+            None => write!(f, "{} {}", self.frame_type, self.start)
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FrameType {
+    Range,
+    Rows,
+    Groups
+}
+
+impl fmt::Display for FrameType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FrameType::Range => write!(f, "range"),
+            FrameType::Rows => write!(f, "rows"),
+            FrameType::Groups => write!(f, "groups")
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FrameBound {
+    UnboundedPreceding,
+    Preceding(Box<Expression>),
+    CurrentRow,
+    Following(Box<Expression>),
+    UnboundedFollowing
+}
+
+impl fmt::Display for FrameBound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FrameBound::UnboundedPreceding => write!(f, "unbounded preceding"),
+            FrameBound::Preceding(offset) => {
+                write_predicate_operand(f, offset)?;
+                write!(f, " preceding")
+            },
+            FrameBound::CurrentRow => write!(f, "current row"),
+            FrameBound::Following(offset) => {
+                write_predicate_operand(f, offset)?;
+                write!(f, " following")
+            },
+            FrameBound::UnboundedFollowing => write!(f, "unbounded following")
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FunctionArgument {
+    Positional(Expression),
+    // name => value
+    Named(String, Expression)
+}
+
+impl fmt::Display for FunctionArgument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FunctionArgument::Positional(value) => write!(f, "{}", value),
+            FunctionArgument::Named(name, value) => write!(f, "{} => {}", name, value)
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum BooleanExpression {
     BinaryExpression {
@@ -73,22 +786,80 @@ impl BooleanExpression {
     }
 
     pub fn not(operand: Expression) -> Expression {
+        BooleanExpression::unary(UnaryOperator::Not, operand)
+    }
+
+    pub fn binary(lhs: Expression, operator: BinaryOperator, rhs: Expression) -> Expression {
+        BooleanExpression::BinaryExpression {
+            lhs: Box::new(lhs),
+            operator,
+            rhs: Box::new(rhs)
+        }.into()
+    }
+
+    pub fn unary(operator: UnaryOperator, operand: Expression) -> Expression {
         BooleanExpression::UnaryExpression {
-            operator: UnaryOperator::Not,
+            operator,
             operand: Box::new(operand)
         }.into()
     }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            BooleanExpression::BinaryExpression { operator, .. } => operator.precedence(),
+            BooleanExpression::UnaryExpression { operator, .. } => operator.precedence()
+        }
+    }
+}
+
+// Predicates bind like comparisons
+const PREDICATE_PRECEDENCE: u8 = 4;
+
+// Precedence of the outermost operator of an expression, anything which is
+// not an operator application binds tighter than every operator.
+fn precedence(expression: &Expression) -> u8 {
+    match expression {
+        Expression::BooleanExpr(b) => b.precedence(),
+        Expression::Between { .. }
+        | Expression::InList { .. }
+        | Expression::InSubquery { .. }
+        | Expression::Like { .. }
+        | Expression::IsNull { .. }
+        | Expression::IsDistinctFrom { .. }
+        | Expression::QuantifiedComparison { .. } => PREDICATE_PRECEDENCE,
+        _ => u8::MAX
+    }
+}
+
+// Writes `expression`, wrapping it in parentheses when `parenthesize` holds
+fn write_operand(f: &mut fmt::Formatter, expression: &Expression, parenthesize: bool) -> fmt::Result {
+    if parenthesize {
+        write!(f, "({})", expression)
+    } else {
+        write!(f, "{}", expression)
+    }
 }
 
 impl fmt::Display for BooleanExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let own = self.precedence();
         match self {
             BooleanExpression::BinaryExpression {
                 lhs, operator, rhs
-            } => write!(f, "{} {} {}", lhs, operator, rhs),
+            } => {
+                // Operators are left associative except comparisons which
+                // do not associate at all
+                let lhs_precedence = precedence(lhs);
+                write_operand(f, lhs, lhs_precedence < own || (lhs_precedence == own && operator.is_comparison()))?;
+                write!(f, " {} ", operator)?;
+                write_operand(f, rhs, precedence(rhs) <= own)
+            },
             BooleanExpression::UnaryExpression {
                 operator, operand
-            } => write!(f, "{} {}", operator, operand),
+            } => {
+                write!(f, "{} ", operator)?;
+                write_operand(f, operand, precedence(operand) < own)
+            },
         }
     }
 }
@@ -117,6 +888,34 @@ pub enum BinaryOperator {
     Substract,
 }
 
+impl BinaryOperator {
+    pub fn precedence(self) -> u8 {
+        match self {
+            BinaryOperator::Or => 1,
+            BinaryOperator::And => 2,
+            BinaryOperator::Equals
+            | BinaryOperator::NotEquals
+            | BinaryOperator::Less
+            | BinaryOperator::LessEquals
+            | BinaryOperator::Greater
+            | BinaryOperator::GreaterEquals
+            | BinaryOperator::Is
+            | BinaryOperator::IsNot => 4,
+            BinaryOperator::BitwiseAnd
+            | BinaryOperator::BitwiseOr
+            | BinaryOperator::LeftShift
+            | BinaryOperator::RightShift => 5,
+            BinaryOperator::Concat => 6,
+            BinaryOperator::Add | BinaryOperator::Substract => 7,
+            BinaryOperator::Multiply | BinaryOperator::Divide | BinaryOperator::Modulus => 8,
+        }
+    }
+
+    pub fn is_comparison(self) -> bool {
+        self.precedence() == 4
+    }
+}
+
 impl fmt::Display for BinaryOperator {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let s = match self {
@@ -158,6 +957,15 @@ pub enum UnaryOperator {
     Positive,
 }
 
+impl UnaryOperator {
+    pub fn precedence(self) -> u8 {
+        match self {
+            UnaryOperator::Not => 3,
+            _ => 9
+        }
+    }
+}
+
 impl fmt::Display for UnaryOperator {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let s = match self {
@@ -166,7 +974,7 @@ impl fmt::Display for UnaryOperator {
             // negative-sign
             UnaryOperator::Negative => "-",
             // "NOT"
-            UnaryOperator::Not => "!",
+            UnaryOperator::Not => "not",
             // positive-sign
             UnaryOperator::Positive => "+"
         };
@@ -183,4 +991,85 @@ pub enum Literal {
     CurrentTime,
     CurrentDate,
     CurrentTimestamp,
+    Boolean(bool),
+    // DATE 'value'
+    Date(String),
+    // TIME 'value'
+    Time(String),
+    // TIMESTAMP 'value'
+    Timestamp(String),
+    // CHAR 'value'
+    Char(String),
+    // any other `type 'value'`, e.g. DECIMAL '1.5' or VARCHAR 'abc'
+    Generic {
+        type_name: String,
+        value: String
+    },
+    // INTERVAL [+|-] 'value' start [TO end]
+    Interval {
+        value: String,
+        sign: Sign,
+        start: IntervalField,
+        end: Option<IntervalField>
+    },
+}
+
+impl Literal {
+    // Literal for `type_name 'value'`, picking the dedicated variant for well known types
+    pub fn typed(type_name: String, value: String) -> Literal {
+        match type_name.to_lowercase().as_str() {
+            "date" => Literal::Date(value),
+            "time" => Literal::Time(value),
+            "timestamp" => Literal::Timestamp(value),
+            "char" => Literal::Char(value),
+            _ => Literal::Generic { type_name, value }
+        }
+    }
+}
+
+impl fmt::Display for Literal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Literal::Numeric(value) => write!(f, "{}", value),
+            // String values are kept as written, quotes are still escaped
+            Literal::String(value) => write!(f, "'{}'", value),
+            Literal::Blob(value) => write!(f, "X'{}'", value),
+            Literal::Null => write!(f, "null"),
+            Literal::CurrentTime => write!(f, "current_time"),
+            Literal::CurrentDate => write!(f, "current_date"),
+            Literal::CurrentTimestamp => write!(f, "current_timestamp"),
+            Literal::Boolean(value) => write!(f, "{}", value),
+            Literal::Date(value) => write!(f, "date '{}'", value),
+            Literal::Time(value) => write!(f, "time '{}'", value),
+            Literal::Timestamp(value) => write!(f, "timestamp '{}'", value),
+            Literal::Char(value) => write!(f, "char '{}'", value),
+            Literal::Generic {
+                type_name, value
+            } => write!(f, "{} '{}'", type_name, value),
+            Literal::Interval {
+                value, sign, start, end
+            } => {
+                write!(f, "interval {}'{}' {}", sign, value, start)?;
+                match end {
+                    Some(end) => write!(f, " to {}", end),
+                    None => Ok(())
+                }
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Sign {
+    Positive,
+    Negative
+}
+
+impl fmt::Display for Sign {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Sign::Positive => Ok(()),
+            Sign::Negative => write!(f, "-")
+        }
+    }
 }
@@ -1,5 +1,8 @@
 pub mod node;
 pub mod basic_ast;
 pub mod expression;
+#[cfg(test)]
+#[allow(non_snake_case)] // test names follow Presto's TestSqlParser
 mod test;
+#[cfg(test)]
 mod tests_expression;
@@ -11,6 +11,7 @@ pub trait NodeTrait: Display + Debug {
     fn get_children(&self) -> Vec<Node>;
 }
 
+#[allow(clippy::large_enum_variant)]
 pub enum Node {
     Select(Select),
     SelectItem(SelectItem),
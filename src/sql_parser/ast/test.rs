@@ -1,5 +1,16 @@
-use crate::sql_parser::parser::{parseStatement, parseExpression};
-#[cfg(test)]
+use crate::sql_parser::parser::{parseStatement, parseStatements, parseExpression};
+use crate::sql_parser::token::{self, ErrorCode};
+use lalrpop_util::ParseError;
+use crate::sql_parser::ast::basic_ast::{BindError, ColumnDefinition, ColumnName, ExplainFormat, ExplainOption,
+                                        ExplainType, GroupBy, GroupingElement, IntervalField, IsolationLevel,
+                                        JoinCriteria, JoinType, LikePattern, LikeProperties, Limit, MergeCase, Offset,
+                                        Property, QualifiedName, QueryBody, QueryPrimary, QueryTerm, Relation,
+                                        RowKeyword, SampleType, Select, SelectItem, SetOperator, ShowCreateType,
+                                        Statement, TableConstraint, TableElement, TransactionMode, Type,
+                                        UpdateAssignment};
+use crate::sql_parser::ast::expression::{BinaryOperator, BooleanExpression, ComparisonQuantifier, Expression, FrameBound,
+                                         FrameType, FunctionArgument, FunctionCall, Literal, Sign, UnaryOperator, WhenClause,
+                                         Window, WindowDefinition, WindowFrame, WindowSpecification};
 
 #[test]
 fn test_set_operator_query() {
@@ -35,6 +46,35 @@ fn testLiterals() {
     assertExpression("INTERVAL '33' day");
     assertExpression("INTERVAL '33' day to second");
     assertExpression("CHAR 'abc'");
+
+    assertExpression("TRUE");
+    assertExpression("false");
+    assertExpression("DATE '2012-01-01'");
+    assertExpression("DECIMAL '12.34'");
+    assertExpression("INTERVAL '3' YEAR");
+    assertExpression("INTERVAL -'3' MONTH");
+    assertExpression("INTERVAL +'1 02:03' DAY TO MINUTE");
+    assertInvalidExpression("INTERVAL '33'");
+    assertInvalidExpression("INTERVAL '33' day to");
+    assertInvalidExpression("TIMESTAMP");
+
+    // INTERVAL is non-reserved, so without a literal after it it names a column
+    assertStatement("SELECT interval, interval - 1 FROM t AS interval");
+    assert_eq!(parseExpression("interval").unwrap(), Expression::Identifier { name: "interval".to_string() });
+
+    assert_eq!(parseExpression("date '2012-01-01'").unwrap(),
+               Expression::Literal(Literal::Date("2012-01-01".to_string())));
+    assert_eq!(parseExpression("TIMESTAMP '2012-01-01 00:00:00'").unwrap(),
+               Expression::Literal(Literal::Timestamp("2012-01-01 00:00:00".to_string())));
+    assert_eq!(parseExpression("DECIMAL '12.34'").unwrap(),
+               Expression::Literal(Literal::Generic { type_name: "DECIMAL".to_string(), value: "12.34".to_string() }));
+    assert_eq!(parseExpression("INTERVAL - '33' day to second").unwrap(),
+               Expression::Literal(Literal::Interval {
+                   value: "33".to_string(),
+                   sign: Sign::Negative,
+                   start: IntervalField::Day,
+                   end: Some(IntervalField::Second)
+               }));
 }
 
 #[test]
@@ -50,6 +90,77 @@ fn testArrayConstructor() {
 fn testArraySubscript() {
     assertExpression("ARRAY [1, 2][1]");
     assertInvalidExpression("CASE WHEN TRUE THEN ARRAY[1,2] END[1]");
+
+    assertExpression("a[1]");
+    assertExpression("a.b[i + 1][2]");
+    assertExpression("f(x)[1]");
+    assertExpression("(CASE WHEN a THEN ARRAY[1,2] END)[1]");
+    assertExpression("'abc'[1]");
+    assertInvalidExpression("a[]");
+    assertInvalidExpression("a[1");
+
+    assertStatement("SELECT day[1]");
+    assertStatement("SELECT year[2] FROM t");
+    assert_eq!(parseExpression("day[1]").unwrap(), Expression::Subscript {
+        base: Box::new(Expression::Identifier { name: "day".to_string() }),
+        index: Box::new(Expression::Literal(Literal::Numeric("1".to_string())))
+    });
+    match parseStatement("SELECT year[2] FROM t").unwrap() {
+        Statement::Query { body, .. } => assert_eq!(body.to_string(), "select year[2] from t"),
+        other => panic!("Expected Query but found {:?}", other)
+    }
+
+    let a = || Expression::Identifier { name: "a".to_string() };
+    assert_eq!(parseExpression("a[b][c]").unwrap(), Expression::Subscript {
+        base: Box::new(Expression::Subscript {
+            base: Box::new(a()),
+            index: Box::new(Expression::Identifier { name: "b".to_string() })
+        }),
+        index: Box::new(Expression::Identifier { name: "c".to_string() })
+    });
+}
+
+#[test]
+fn testMapConstructor() {
+    assertExpression("MAP(ARRAY[1, 2], ARRAY['a', 'b'])");
+    assertExpression("map(k, v)[1]");
+    assertInvalidExpression("MAP(ARRAY[1])");
+    assertInvalidExpression("MAP[1, 2]");
+}
+
+#[test]
+fn testRowConstructor() {
+    assertExpression("ROW(1)");
+    assertExpression("ROW(1, 'a', ARRAY[x])");
+    assertExpression("ROW(a, b).x");
+    assertInvalidExpression("ROW()");
+}
+
+#[test]
+fn testDereference() {
+    assertExpression("a.b");
+    assertExpression("a.b.c.d");
+    assertExpression("f(x).field");
+    assertExpression("(a + b).field");
+    assertExpression("a[1].field.other");
+    assertExpression("CAST(x AS ROW(a bigint)).a");
+    assertInvalidExpression("a.");
+    assertInvalidExpression("a.1");
+
+    let a_b_c = Expression::Dereference {
+        base: Box::new(Expression::Dereference {
+            base: Box::new(Expression::Identifier { name: "a".to_string() }),
+            field: "b".to_string()
+        }),
+        field: "c".to_string()
+    };
+    assert_eq!(parseExpression("a.b.c").unwrap(), a_b_c);
+}
+
+#[test]
+fn testBracketedIdentifier() {
+    assertStatement("SELECT [my column] FROM [my table]");
+    assertStatement("SELECT a FROM t WHERE [b c] = a[1]");
 }
 
 #[test]
@@ -124,6 +235,31 @@ fn testCast() {
     assertCast("array<ROW(x BIGINT, y TIMESTAMP)>");
 
     assertCast("interval year to month");
+    assertCast("interval day");
+    assertCast("time with time zone(3)");
+    assertCast("timestamp(6) with time zone");
+    assertCast("map<varchar, map<bigint, array<double>>>");
+    assertCast("array<array<array<bigint>>>");
+
+    assertInvalidExpression("CAST(null AS array<bigint>>)");
+    assertInvalidExpression("CAST(null AS interval year to)");
+    assertInvalidExpression("CAST(null AS timestamp(6) with time zone(3))");
+    assertInvalidExpression("CAST(null AS varchar with time zone)");
+    assertInvalidExpression("CAST(null AS varchar())");
+    assertInvalidExpression("CAST(null)");
+    assertStatement("SELECT try_cast, try_cast.a FROM try_cast");
+
+    let bigint = || Type::User_Defined("bigint".to_string(), None);
+    let nested_array = Type::Array(Box::new(Type::Array(Box::new(bigint()))));
+    for type_str in ["bigint ARRAY ARRAY", "array<array<bigint>>", "array(array(bigint))", "ARRAY<bigint> ARRAY"].iter() {
+        match parseExpression(&("CAST(x AS ".to_string() + type_str + ")")).unwrap() {
+            Expression::Cast { data_type, safe, .. } => {
+                assert_eq!(data_type, nested_array);
+                assert!(!safe);
+            }
+            other => panic!("Expected Cast but found {:?}", other)
+        }
+    }
 }
 
 #[test]
@@ -161,18 +297,1573 @@ fn testArithmeticUnary() {
     assertExpression("- - - 9");
 }
 
-//@Test
-//public void testCoalesce()
-//{
-//assertInvalidExpression("coalesce()", "The 'coalesce' function must have at least two arguments");
-//assertInvalidExpression("coalesce(5)", "The 'coalesce' function must have at least two arguments");
-//assertExpression("coalesce(13, 42)", new CoalesceExpression(new LongLiteral("13"), new LongLiteral("42")));
-//assertExpression("coalesce(6, 7, 8)", new CoalesceExpression(new LongLiteral("6"), new LongLiteral("7"), new LongLiteral("8")));
-//assertExpression("coalesce(13, null)", new CoalesceExpression(new LongLiteral("13"), new NullLiteral()));
-//assertExpression("coalesce(null, 13)", new CoalesceExpression(new NullLiteral(), new LongLiteral("13")));
-//assertExpression("coalesce(null, null)", new CoalesceExpression(new NullLiteral(), new NullLiteral()));
-//}
-//
+#[test]
+fn testArithmeticBinary() {
+    assertExpression("a + b");
+    assertExpression("a - b * c / d % e");
+    assertExpression("a || b || c");
+    assertExpression("a & b | c << 2 >> 1");
+    assertExpression("(a + b) * (c - d)");
+    assertExpression("-(a + b)");
+    assertExpression("~a");
+    assertInvalidExpression("a +");
+    assertInvalidExpression("(a + b");
+}
+
+#[test]
+fn testComparison() {
+    assertExpression("a = b");
+    assertExpression("a <> b");
+    assertExpression("a != b");
+    assertExpression("a < b");
+    assertExpression("a <= b");
+    assertExpression("a > b");
+    assertExpression("a >= b");
+    assertExpression("a + 1 >= b * 2");
+    assertInvalidExpression("a = b = c");
+    assertInvalidExpression("a => b");
+}
+
+#[test]
+fn testLambda() {
+    assertExpression("transform(arr, x -> x + 1)");
+    assertExpression("filter(arr, x -> x > 0)");
+    assertExpression("reduce(arr, 0, (s, x) -> s + x, s -> s)");
+    assertExpression("transform(arr, (x) -> x * 2)");
+    assertExpression("transform(arr, x -> transform(x, y -> x + y))");
+    assertExpression("transform(items, r -> r.a[1])");
+    assertExpression("zip_with(a, b, (x, y) -> CASE WHEN x > y THEN x ELSE y END)");
+    assertStatement("SELECT filter(a, x -> x IS NOT NULL) FROM t WHERE any_match(a, x -> x = 1)");
+    assertStatement("SELECT filter(a, x -> x > 0) OVER (PARTITION BY b) FROM t");
+    assertStatement("SELECT filter(DISTINCT a, x -> x > 0) FILTER (WHERE b) FROM t");
+    assertStatement("SELECT filter, filter[1], t.filter AS filter FROM filter t");
+    assertInvalidExpression("x -> x");
+    assertInvalidExpression("transform(arr, x + 1 -> x)");
+    assertInvalidExpression("transform(arr, t.x -> x)");
+    assertInvalidExpression("transform(arr, () -> 1)");
+    assertInvalidExpression("transform(arr, x ->)");
+
+    assert!(matches!(parseExpression("transform(arr, t.x -> x)"),
+                     Err(ParseError::User { error: token::Error { code: ErrorCode::BadLambdaArgument, .. } })));
+
+    let argument = |name: &str| Expression::LambdaArgument { name: name.to_string() };
+    let call = |name: &str, arguments: Vec<Expression>| Expression::FunctionCall(FunctionCall {
+        name: QualifiedName { name: vec![name.to_string()] },
+        distinct: false,
+        all_rows: false,
+        arguments: arguments.into_iter().map(FunctionArgument::Positional).collect(),
+        order_by: None,
+        filter: None,
+        window: None
+    });
+    assert_eq!(parseExpression("reduce(arr, 0, (s, x) -> s + x, s -> s)").unwrap(),
+               call("reduce", vec![
+                   Expression::Identifier { name: "arr".to_string() },
+                   Expression::Literal(Literal::Numeric("0".to_string())),
+                   Expression::Lambda {
+                       arguments: vec!["s".to_string(), "x".to_string()],
+                       body: Box::new(BooleanExpression::binary(argument("s"), BinaryOperator::Add, argument("x")))
+                   },
+                   Expression::Lambda { arguments: vec!["s".to_string()], body: Box::new(argument("s")) }
+               ]));
+    // `y` is not an argument, and the inner `x` refers to the inner lambda
+    assert_eq!(parseExpression("f(x -> g(x, y, x -> x))").unwrap(),
+               call("f", vec![Expression::Lambda {
+                   arguments: vec!["x".to_string()],
+                   body: Box::new(call("g", vec![
+                       argument("x"),
+                       Expression::Identifier { name: "y".to_string() },
+                       Expression::Lambda { arguments: vec!["x".to_string()], body: Box::new(argument("x")) }
+                   ]))
+               }]));
+}
+
+#[test]
+fn testBetween() {
+    assertExpression("1 BETWEEN 2 AND 3");
+    assertExpression("1 NOT BETWEEN 2 AND 3");
+    assertExpression("a + 1 BETWEEN b - 1 AND c * 2 AND d");
+    assertInvalidExpression("1 BETWEEN 2");
+    assertInvalidExpression("1 BETWEEN 2 AND 3 BETWEEN 4 AND 5");
+
+    let number = |value: &str| Expression::Literal(Literal::Numeric(value.to_string()));
+    assert_eq!(parseExpression("1 NOT BETWEEN 2 AND 3").unwrap(),
+               Expression::Between {
+                   value: Box::new(number("1")),
+                   min: Box::new(number("2")),
+                   max: Box::new(number("3")),
+                   not: true
+               });
+    // the second AND belongs to the conjunction
+    assert_eq!(parseExpression("a BETWEEN b AND c AND d").unwrap(),
+               BooleanExpression::and(
+                   Expression::Between {
+                       value: Box::new(Expression::Identifier { name: "a".to_string() }),
+                       min: Box::new(Expression::Identifier { name: "b".to_string() }),
+                       max: Box::new(Expression::Identifier { name: "c".to_string() }),
+                       not: false
+                   },
+                   Expression::Identifier { name: "d".to_string() }));
+}
+
+#[test]
+fn testIn() {
+    assertExpression("a IN (1)");
+    assertExpression("a IN (1, 2, 3)");
+    assertExpression("a NOT IN ('x', 'y')");
+    assertExpression("a IN (SELECT b FROM t)");
+    assertExpression("a NOT IN (SELECT b FROM t WHERE c > 1)");
+    assertExpression("a IN ((SELECT b FROM t), 2)");
+    assertInvalidExpression("a IN ()");
+    assertInvalidExpression("a IN 1");
+
+    let a = Box::new(Expression::Identifier { name: "a".to_string() });
+    assert_eq!(parseExpression("a NOT IN (1, 2)").unwrap(),
+               Expression::InList {
+                   value: a.clone(),
+                   list: vec![Expression::Literal(Literal::Numeric("1".to_string())),
+                              Expression::Literal(Literal::Numeric("2".to_string()))],
+                   not: true
+               });
+    assert_eq!(parseExpression("a IN (SELECT b FROM t)").unwrap(),
+               Expression::InSubquery {
+                   value: a,
+                   subquery: Box::new(parseStatement("SELECT b FROM t").unwrap()),
+                   not: false
+               });
+}
+
+#[test]
+fn testLike() {
+    assertExpression("a LIKE 'abc%'");
+    assertExpression("a NOT LIKE 'abc%'");
+    assertExpression("a LIKE 'a\\_%' ESCAPE '\\'");
+    assertExpression("a || b NOT LIKE c || '%' ESCAPE e");
+    assertInvalidExpression("a LIKE");
+    assertInvalidExpression("a LIKE 'b' ESCAPE");
+
+    assert_eq!(parseExpression("a NOT LIKE 'b%' ESCAPE '!'").unwrap(),
+               Expression::Like {
+                   value: Box::new(Expression::Identifier { name: "a".to_string() }),
+                   pattern: Box::new(Expression::Literal(Literal::String("b%".to_string()))),
+                   escape: Some(Box::new(Expression::Literal(Literal::String("!".to_string())))),
+                   not: true
+               });
+}
+
+#[test]
+fn testNullPredicate() {
+    assertExpression("a IS NULL");
+    assertExpression("a IS NOT NULL");
+    assertExpression("a + 1 IS NULL AND b IS NOT NULL");
+    assertExpression("a ISNULL");
+    assertExpression("a NOTNULL");
+    assertInvalidExpression("a IS");
+    assertInvalidExpression("a IS NULL IS NULL");
+
+    assert_eq!(parseExpression("a IS NOT NULL").unwrap(), parseExpression("a NOTNULL").unwrap());
+    assert_eq!(parseExpression("NOT a IS NULL").unwrap(),
+               BooleanExpression::not(Expression::IsNull {
+                   value: Box::new(Expression::Identifier { name: "a".to_string() }),
+                   not: false
+               }));
+}
+
+#[test]
+fn testDistinctFrom() {
+    assertExpression("a IS DISTINCT FROM b");
+    assertExpression("a IS NOT DISTINCT FROM b");
+    assertInvalidExpression("a IS DISTINCT b");
+    assertInvalidExpression("a DISTINCT FROM b");
+
+    assert_eq!(parseExpression("a IS NOT DISTINCT FROM b").unwrap(),
+               Expression::IsDistinctFrom {
+                   lhs: Box::new(Expression::Identifier { name: "a".to_string() }),
+                   rhs: Box::new(Expression::Identifier { name: "b".to_string() }),
+                   not: true
+               });
+}
+
+#[test]
+fn testExists() {
+    assertExpression("EXISTS (SELECT a FROM t)");
+    assertExpression("NOT EXISTS (SELECT a FROM t WHERE b = c)");
+    assertStatement("SELECT a FROM t WHERE EXISTS (SELECT b FROM u)");
+    assertInvalidExpression("EXISTS (1)");
+    assertInvalidExpression("EXISTS SELECT a FROM t");
+
+    assert_eq!(parseExpression("EXISTS (SELECT a FROM t)").unwrap(),
+               Expression::Exists(Box::new(parseStatement("SELECT a FROM t").unwrap())));
+}
+
+#[test]
+fn testQuantifiedComparison() {
+    assertExpression("col1 < ANY (SELECT col2 FROM table1)");
+    assertExpression("col1 = ALL (SELECT col2 FROM table1)");
+    assertExpression("col1 = SOME (SELECT col2 FROM table1)");
+    assertExpression("col1 >= SOME (SELECT col2 FROM table1)");
+    assertInvalidExpression("col1 = ANY (1, 2)");
+    assertInvalidExpression("col1 ANY (SELECT col2 FROM table1)");
+    assertExpression("any = some");
+    assertStatement("SELECT any, some FROM t AS some");
+
+    assert_eq!(parseExpression("col1 <> ALL (SELECT col2 FROM table1)").unwrap(),
+               Expression::QuantifiedComparison {
+                   lhs: Box::new(Expression::Identifier { name: "col1".to_string() }),
+                   operator: BinaryOperator::NotEquals,
+                   quantifier: ComparisonQuantifier::All,
+                   subquery: Box::new(parseStatement("SELECT col2 FROM table1").unwrap())
+               });
+}
+
+#[test]
+fn testSubqueryExpression() {
+    assertExpression("(SELECT a FROM t)");
+    assertExpression("(SELECT a FROM t) + 1");
+    assertExpression("a = (SELECT max(b) FROM t)");
+    assertStatement("SELECT (SELECT b FROM u) FROM t");
+
+    assert_eq!(parseExpression("(SELECT a FROM t)").unwrap(),
+               Expression::Subquery(Box::new(parseStatement("SELECT a FROM t").unwrap())));
+}
+
+#[test]
+fn testPrecedenceAndAssociativity() {
+    let a = || Expression::Identifier { name: "a".to_string() };
+    let b = || Expression::Identifier { name: "b".to_string() };
+    let c = || Expression::Identifier { name: "c".to_string() };
+
+    assert_eq!(parseExpression("a or b and c").unwrap(),
+               BooleanExpression::or(a(), BooleanExpression::and(b(), c())));
+    assert_eq!(parseExpression("a and b or c").unwrap(),
+               BooleanExpression::or(BooleanExpression::and(a(), b()), c()));
+    assert_eq!(parseExpression("not a and b").unwrap(),
+               BooleanExpression::and(BooleanExpression::not(a()), b()));
+    assert_eq!(parseExpression("not a = b").unwrap(),
+               BooleanExpression::not(BooleanExpression::binary(a(), BinaryOperator::Equals, b())));
+    assert_eq!(parseExpression("a = b or c").unwrap(),
+               BooleanExpression::or(BooleanExpression::binary(a(), BinaryOperator::Equals, b()), c()));
+    assert_eq!(parseExpression("a + b * c").unwrap(),
+               BooleanExpression::binary(a(), BinaryOperator::Add,
+                                         BooleanExpression::binary(b(), BinaryOperator::Multiply, c())));
+    assert_eq!(parseExpression("a - b - c").unwrap(),
+               BooleanExpression::binary(BooleanExpression::binary(a(), BinaryOperator::Substract, b()),
+                                         BinaryOperator::Substract, c()));
+    assert_eq!(parseExpression("a || b + c").unwrap(),
+               BooleanExpression::binary(a(), BinaryOperator::Concat,
+                                         BooleanExpression::binary(b(), BinaryOperator::Add, c())));
+    assert_eq!(parseExpression("-a * b").unwrap(),
+               BooleanExpression::binary(BooleanExpression::unary(UnaryOperator::Negative, a()),
+                                         BinaryOperator::Multiply, b()));
+    assert_eq!(parseExpression("(a + b) * c").unwrap(),
+               BooleanExpression::binary(BooleanExpression::binary(a(), BinaryOperator::Add, b()),
+                                         BinaryOperator::Multiply, c()));
+}
+
+#[test]
+fn testFunctionCall() {
+    assertExpression("now()");
+    assertExpression("count(*)");
+    assertExpression("count(x)");
+    assertExpression("count(DISTINCT x)");
+    assertExpression("count(ALL x)");
+    assertExpression("catalog.db.f(a, b + 1, c)");
+    assertExpression("sum(x) FILTER (WHERE y)");
+    assertExpression("count(*) FILTER (WHERE y > 0)");
+    assertExpression("array_agg(x ORDER BY y)");
+    assert_ne!(parseExpression("f()").unwrap(), parseExpression("f(*)").unwrap());
+    assertExpression("array_agg(DISTINCT x ORDER BY y DESC, z) FILTER (WHERE x <> 0)");
+    assertExpression("f(a => 1)");
+    assertExpression("f(1, a => 2, b => x + y)");
+    assertExpression("f(g(h(x)))");
+    assertInvalidExpression("count(DISTINCT)");
+    assertInvalidExpression("count(DISTINCT *)");
+    assertInvalidExpression("f(a =>)");
+    assertInvalidExpression("sum(x) FILTER (y)");
+
+    let x = Expression::Identifier { name: "x".to_string() };
+    assert_eq!(parseExpression("count(DISTINCT x)").unwrap(), Expression::FunctionCall(FunctionCall {
+        name: QualifiedName { name: vec!["count".to_string()] },
+        distinct: true,
+        all_rows: false,
+        arguments: vec![FunctionArgument::Positional(x.clone())],
+        order_by: None,
+        filter: None,
+        window: None
+    }));
+    assert_eq!(parseExpression("f(a => x)").unwrap(), Expression::FunctionCall(FunctionCall {
+        name: QualifiedName { name: vec!["f".to_string()] },
+        distinct: false,
+        all_rows: false,
+        arguments: vec![FunctionArgument::Named("a".to_string(), x)],
+        order_by: None,
+        filter: None,
+        window: None
+    }));
+}
+
+#[test]
+fn testWindowFunction() {
+    assertExpression("rank() OVER ()");
+    assertExpression("rank() OVER (PARTITION BY a ORDER BY b ROWS BETWEEN 2 PRECEDING AND CURRENT ROW)");
+    assertExpression("sum(x) FILTER (WHERE x > 0) OVER (PARTITION BY a, b)");
+    assertExpression("count(*) OVER w");
+    assertExpression("avg(x) OVER (w ORDER BY b DESC LAST)");
+    assertExpression("row_number() OVER (ORDER BY a) + 1");
+    assertInvalidExpression("rank() OVER");
+    assertInvalidExpression("rank() OVER (PARTITION a)");
+    assertInvalidExpression("rank() OVER (ORDER BY a PARTITION BY b)");
+
+    assert_eq!(parseExpression("count(*) OVER (w PARTITION BY a)").unwrap(), Expression::FunctionCall(FunctionCall {
+        name: QualifiedName { name: vec!["count".to_string()] },
+        distinct: false,
+        all_rows: true,
+        arguments: vec![],
+        order_by: None,
+        filter: None,
+        window: Some(Window::Specification(WindowSpecification {
+            existing_window: Some("w".to_string()),
+            partition_by: vec![Expression::Identifier { name: "a".to_string() }],
+            order_by: None,
+            frame: None
+        }))
+    }));
+}
+
+#[test]
+fn testWindowFrame() {
+    assertExpression("sum(x) OVER (ORDER BY a ROWS UNBOUNDED PRECEDING)");
+    assertExpression("sum(x) OVER (ORDER BY a RANGE CURRENT ROW)");
+    assertExpression("sum(x) OVER (ORDER BY a GROUPS 1 + 1 PRECEDING)");
+    assertExpression("sum(x) OVER (ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING)");
+    assertExpression("sum(x) OVER (RANGE BETWEEN INTERVAL '1' DAY PRECEDING AND 2 FOLLOWING)");
+    assertExpression("sum(x) OVER (ROWS BETWEEN CURRENT ROW AND n FOLLOWING)");
+    assertExpression("sum(x) OVER (ROWS BETWEEN preceding PRECEDING AND unbounded + 1 FOLLOWING)");
+    assertInvalidExpression("sum(x) OVER (ROWS)");
+    assertInvalidExpression("sum(x) OVER (ROWS 1)");
+    assertInvalidExpression("sum(x) OVER (ROWS BETWEEN 1 PRECEDING)");
+    assertInvalidExpression("sum(x) OVER (ROWS UNBOUNDED)");
+
+    let frame = |sql: &str| match parseExpression(sql).unwrap() {
+        Expression::FunctionCall(FunctionCall { window: Some(Window::Specification(specification)), .. }) => specification.frame,
+        expression => panic!("unexpected expression {:?}", expression)
+    };
+    assert_eq!(frame("sum(x) OVER (ROWS BETWEEN 2 PRECEDING AND CURRENT ROW)"),
+               Some(WindowFrame {
+                   frame_type: FrameType::Rows,
+                   start: FrameBound::Preceding(Box::new(Expression::Literal(Literal::Numeric("2".to_string())))),
+                   end: Some(FrameBound::CurrentRow)
+               }));
+    assert_eq!(frame("sum(x) OVER (GROUPS UNBOUNDED FOLLOWING)"),
+               Some(WindowFrame { frame_type: FrameType::Groups, start: FrameBound::UnboundedFollowing, end: None }));
+    let unbounded = Box::new(Expression::Identifier { name: "unbounded".to_string() });
+    assert_eq!(frame("sum(x) OVER (RANGE \"unbounded\" PRECEDING)"),
+               Some(WindowFrame { frame_type: FrameType::Range, start: FrameBound::Preceding(unbounded), end: None }));
+}
+
+#[test]
+fn testWindowClause() {
+    assertStatement("SELECT rank() OVER w FROM t WINDOW w AS (PARTITION BY a)");
+    assertStatement("SELECT sum(x) OVER (w2 ROWS CURRENT ROW) FROM t GROUP BY a HAVING a > 1 \
+                     WINDOW w1 AS (ORDER BY a), w2 AS (w1 PARTITION BY b)");
+    assertInvalidStatement("SELECT a FROM t WINDOW w");
+    assertInvalidStatement("SELECT a FROM t WINDOW w AS PARTITION BY a");
+
+    // the window keywords are non-reserved, OVER and WINDOW only make aliases with AS
+    assertStatement("SELECT range, rows, groups, current, preceding, following, unbounded FROM t");
+    assertStatement("SELECT over, window, rows.range FROM rows");
+    assertStatement("SELECT a AS over, b AS window FROM t AS window");
+    assertStatement("SELECT a FROM range r WINDOW groups AS (ORDER BY current)");
+    assertInvalidStatement("SELECT a window FROM t");
+
+    assert_eq!(select("SELECT a FROM t WINDOW w AS ()").windows,
+               Some(vec![WindowDefinition {
+                   name: "w".to_string(),
+                   window: WindowSpecification { existing_window: None, partition_by: vec![], order_by: None, frame: None }
+               }]));
+}
+
+#[test]
+fn testValues() {
+    assertStatement("VALUES ('a', 1, 2.2e0), ('b', 2, 3.3e0)");
+    assertStatement("VALUES (1), (2)");
+    assertStatement("VALUES 1, 2 + 3, (4)");
+    assertStatement("SELECT * FROM (VALUES ('a', 1, 2.2e0), ('b', 2, 3.3e0))");
+    assertStatement("SELECT * FROM (VALUES (1, 2)) AS v(a, b)");
+    assertStatement("SELECT a FROM t UNION ALL VALUES (1) ORDER BY 1");
+    assertExpression("a IN (VALUES 1, 2)");
+    assertInvalidStatement("VALUES");
+    assertInvalidStatement("VALUES ()");
+
+    let number = |value: &str| Expression::Literal(Literal::Numeric(value.to_string()));
+    assert_eq!(parseStatement("VALUES (1, 2), 3").unwrap(),
+               Statement::Query {
+                   with: None,
+                   body: QueryBody {
+                       query_term: QueryTerm::Primary(QueryPrimary::Values(vec![vec![number("1"), number("2")], vec![number("3")]])),
+                       order_by: None,
+                       offset: None,
+                       limit: None
+                   }
+               });
+    assert_eq!(from("SELECT * FROM (VALUES (1, 2)) v (a, b)"),
+               Relation::Aliased {
+                   relation: Box::new(Relation::Subquery(Box::new(parseStatement("VALUES (1, 2)").unwrap()))),
+                   alias: "v".to_string(),
+                   columns: Some(vec![ColumnName { identifier: "a".to_string() }, ColumnName { identifier: "b".to_string() }])
+               });
+}
+
+#[test]
+fn testSetOperations() {
+    assertStatement("(SELECT a FROM t) UNION (SELECT b FROM u ORDER BY b LIMIT 1)");
+    assertStatement("((SELECT a FROM t) INTERSECT SELECT b FROM u) EXCEPT ALL VALUES 1 ORDER BY 1");
+    assertStatement("SELECT a FROM t WHERE a IN (SELECT b FROM u UNION (SELECT c FROM v LIMIT 2))");
+    assertStatement("SELECT * FROM (SELECT a FROM t INTERSECT DISTINCT SELECT b FROM u) x");
+    assertStatement("CREATE TABLE foo AS (SELECT a FROM t) UNION SELECT b FROM u");
+    assertExpression("((SELECT 1))");
+    assertStatement("SELECT * FROM ((SELECT a FROM t) UNION (SELECT b FROM u)) x");
+    assertStatement("SELECT * FROM (((SELECT a FROM t) ORDER BY a LIMIT 1)) x");
+    assertExpression("a IN ((SELECT 1) UNION (SELECT 2))");
+    assertExpression("a IN ((SELECT 1), (SELECT 2))");
+    assertStatement("SELECT ((SELECT 1) UNION SELECT 2)");
+    assertStatement("SELECT ((SELECT 1) INTERSECT SELECT 2) + 1");
+    assertInvalidStatement("SELECT a FROM t UNION");
+    assertInvalidStatement("(SELECT a FROM t");
+
+    // UNION ALL keeps duplicates while a bare UNION is distinct
+    let term = |sql: &str| match parseStatement(sql).unwrap() {
+        Statement::Query { body: QueryBody { query_term, .. }, .. } => query_term,
+        statement => panic!("unexpected statement {:?}", statement)
+    };
+    let primary = |sql: &str| match term(sql) {
+        QueryTerm::Primary(primary) => primary,
+        query_term => panic!("unexpected query term {:?}", query_term)
+    };
+    let set_operation = |left: QueryTerm, operator: SetOperator, distinct: bool, right: QueryTerm| QueryTerm::SetOperation {
+        left: Box::new(left), operator, distinct, right: Box::new(right)
+    };
+    let a = || QueryTerm::Primary(primary("SELECT a FROM t"));
+    let b = || QueryTerm::Primary(primary("SELECT b FROM u"));
+    let c = || QueryTerm::Primary(primary("SELECT c FROM v"));
+
+    assert_eq!(term("SELECT a FROM t UNION ALL SELECT b FROM u"), set_operation(a(), SetOperator::Union, false, b()));
+    assert_eq!(term("SELECT a FROM t UNION SELECT b FROM u"), set_operation(a(), SetOperator::Union, true, b()));
+    assert_eq!(term("SELECT a FROM t UNION DISTINCT SELECT b FROM u"), set_operation(a(), SetOperator::Union, true, b()));
+    assert_eq!(term("SELECT a FROM t UNION SELECT b FROM u INTERSECT SELECT c FROM v"),
+               set_operation(a(), SetOperator::Union, true, set_operation(b(), SetOperator::Intersect, true, c())));
+    assert_eq!(term("SELECT a FROM t INTERSECT ALL SELECT b FROM u EXCEPT SELECT c FROM v"),
+               set_operation(set_operation(a(), SetOperator::Intersect, false, b()), SetOperator::Except, true, c()));
+    assert_eq!(term("SELECT a FROM t EXCEPT SELECT b FROM u UNION ALL SELECT c FROM v"),
+               set_operation(set_operation(a(), SetOperator::Except, true, b()), SetOperator::Union, false, c()));
+
+    match term("(SELECT a FROM t) UNION (SELECT b FROM u ORDER BY b LIMIT 1)") {
+        QueryTerm::SetOperation { left, right, .. } => {
+            match *left {
+                QueryTerm::Primary(QueryPrimary::Subquery(body)) => assert_eq!(body.query_term, a()),
+                other => panic!("Expected a parenthesized query but found {:?}", other)
+            }
+            match *right {
+                QueryTerm::Primary(QueryPrimary::Subquery(body)) => {
+                    assert_eq!(body.query_term, b());
+                    assert!(body.order_by.is_some());
+                    assert!(body.limit.is_some());
+                }
+                other => panic!("Expected a parenthesized query but found {:?}", other)
+            }
+        }
+        other => panic!("Expected a set operation but found {:?}", other)
+    }
+}
+
+#[test]
+fn testLimit() {
+    assertStatement("SELECT a FROM t LIMIT 10");
+    assertStatement("SELECT a FROM t LIMIT ALL");
+    assertStatement("SELECT a FROM t ORDER BY a LIMIT 10 OFFSET 5");
+    assertStatement("SELECT a FROM t LIMIT 5, 10");
+    assertInvalidStatement("SELECT a FROM t LIMIT");
+    assertInvalidStatement("SELECT a FROM t LIMIT ALL OFFSET 5");
+
+    let number = |value: &str| Expression::Literal(Literal::Numeric(value.to_string()));
+    assert_eq!(limit("SELECT a FROM t LIMIT 10"), (None, Some(Limit::Count(number("10")))));
+    assert_eq!(limit("SELECT a FROM t LIMIT ALL"), (None, Some(Limit::All)));
+    assert_eq!(limit("SELECT a FROM t LIMIT 10 OFFSET 5"),
+               (None, Some(Limit::CountOffset { count: number("10"), offset: number("5") })));
+    assert_eq!(limit("SELECT a FROM t LIMIT 5, 10"),
+               (None, Some(Limit::OffsetCount { offset: number("5"), count: number("10") })));
+}
+
+#[test]
+fn testOffset() {
+    assertStatement("SELECT a FROM t OFFSET 2");
+    assertStatement("SELECT a FROM t OFFSET 2 ROWS");
+    assertStatement("SELECT a FROM t ORDER BY a OFFSET 1 ROW LIMIT 3");
+    assertStatement("SELECT a FROM t OFFSET 2 ROWS FETCH NEXT 3 ROWS ONLY");
+    assertInvalidStatement("SELECT a FROM t OFFSET ROW");
+    assertInvalidStatement("SELECT a FROM t OFFSET 1 LIMIT 3 OFFSET 5");
+    assertInvalidStatement("SELECT a FROM t OFFSET 1 LIMIT 5, 10");
+    assertInvalidStatement("SELECT a FROM t ORDER BY a OFFSET 1 LIMIT 3 OFFSET 5");
+
+    let number = |value: &str| Expression::Literal(Literal::Numeric(value.to_string()));
+    assert_eq!(limit("SELECT a FROM t OFFSET 2"), (Some(Offset { row_count: number("2"), rows: None }), None));
+    assert_eq!(limit("SELECT a FROM t OFFSET 1 ROW LIMIT 3"),
+               (Some(Offset { row_count: number("1"), rows: Some(RowKeyword::Row) }), Some(Limit::Count(number("3")))));
+}
+
+#[test]
+fn testFetchFirst() {
+    assertStatement("SELECT a FROM t FETCH FIRST 2 ROWS ONLY");
+    assertStatement("SELECT a FROM t FETCH FIRST ROW ONLY");
+    assertStatement("SELECT a FROM t ORDER BY a FETCH FIRST 2 ROWS WITH TIES");
+    assertInvalidStatement("SELECT a FROM t FETCH FIRST 2 ONLY");
+    assertInvalidStatement("SELECT a FROM t FETCH 2 ROWS ONLY");
+    assertInvalidStatement("SELECT a FROM t FETCH FIRST 2 ROWS");
+    assertStatement("SELECT fetch FROM fetch AS fetch FETCH FIRST fetch ROWS ONLY");
+    assertInvalidStatement("SELECT a fetch FROM t");
+
+    let number = |value: &str| Expression::Literal(Literal::Numeric(value.to_string()));
+    assert_eq!(limit("SELECT a FROM t FETCH FIRST ROW ONLY"),
+               (None, Some(Limit::Fetch { next: false, count: None, rows: RowKeyword::Row, with_ties: false })));
+    assert_eq!(limit("SELECT a FROM t FETCH NEXT 2 ROWS WITH TIES"),
+               (None, Some(Limit::Fetch { next: true, count: Some(number("2")), rows: RowKeyword::Rows, with_ties: true })));
+}
+
+#[test]
+fn testAllColumns() {
+    assertStatement("SELECT * FROM t");
+    assertStatement("SELECT t.*, u.a FROM t, u");
+    assertStatement("SELECT db.t.*, * FROM db.t");
+    assertInvalidStatement("SELECT * AS x FROM t");
+    assertInvalidStatement("SELECT t.* x FROM t");
+
+    assert_eq!(select("SELECT *, db.t.* FROM t").projection,
+               vec![SelectItem::AllColumns(None),
+                    SelectItem::AllColumns(Some(QualifiedName { name: vec!["db".to_string(), "t".to_string()] }))]);
+}
+
+#[test]
+fn testSelectWithoutFrom() {
+    assertStatement("SELECT 1");
+    assertStatement("SELECT 1 + 2, 'a' AS b");
+    assertStatement("SELECT a WHERE a > 1");
+    assertInvalidStatement("SELECT 1 FROM");
+
+    assert_eq!(select("SELECT 1").from, None);
+}
+
+#[test]
+fn testAliasedRelation() {
+    assertStatement("SELECT a FROM t AS x");
+    assertStatement("SELECT a FROM t x");
+    assertStatement("SELECT a FROM catalog.db.t x (c1, c2)");
+    assertStatement("SELECT a FROM (SELECT b FROM t) x");
+    assertStatement("SELECT a FROM (SELECT b, c FROM t) AS x (b1, c1)");
+    assertStatement("SELECT a FROM (t) x");
+    assertInvalidStatement("SELECT a FROM t AS");
+    assertInvalidStatement("SELECT a FROM t x ()");
+
+    assert_eq!(from("SELECT a FROM db.t AS x (b)"),
+               Relation::Aliased {
+                   relation: Box::new(table("db.t")),
+                   alias: "x".to_string(),
+                   columns: Some(vec![ColumnName { identifier: "b".to_string() }])
+               });
+    assert_eq!(from("SELECT a FROM (SELECT b FROM t)"),
+               Relation::Subquery(Box::new(parseStatement("SELECT b FROM t").unwrap())));
+}
+
+#[test]
+fn testImplicitJoin() {
+    assertStatement("SELECT a FROM t, u");
+    assertStatement("SELECT a FROM t x, u y, v WHERE x.a = y.b");
+
+    assert_eq!(from("SELECT a FROM t, u, v"),
+               join(JoinType::Implicit, join(JoinType::Implicit, table("t"), table("u"), None), table("v"), None));
+    // comma binds looser than explicit joins
+    assert_eq!(from("SELECT a FROM t, u JOIN v USING (k)"),
+               join(JoinType::Implicit,
+                    table("t"),
+                    join(JoinType::Inner, table("u"), table("v"),
+                         Some(JoinCriteria::Using(vec![ColumnName { identifier: "k".to_string() }]))),
+                    None));
+}
+
+#[test]
+fn testJoinCriteria() {
+    assertStatement("SELECT a FROM t JOIN u ON t.a = u.b");
+    assertStatement("SELECT a FROM t INNER JOIN u ON t.a = u.b AND t.c > 1");
+    assertStatement("SELECT a FROM t LEFT JOIN u USING (a, b)");
+    assertStatement("SELECT a FROM t LEFT OUTER JOIN u ON true");
+    assertStatement("SELECT a FROM t RIGHT OUTER JOIN u ON true");
+    assertStatement("SELECT a FROM t FULL JOIN u ON true");
+    assertStatement("SELECT a FROM t NATURAL JOIN u");
+    assertStatement("SELECT a FROM t NATURAL FULL OUTER JOIN u");
+    assertStatement("SELECT a FROM t CROSS JOIN u");
+    assertInvalidStatement("SELECT a FROM t JOIN u");
+    assertInvalidStatement("SELECT a FROM t CROSS JOIN u ON true");
+    assertInvalidStatement("SELECT a FROM t NATURAL JOIN u USING (a)");
+    assertInvalidStatement("SELECT a FROM t LEFT JOIN u USING ()");
+
+    assert_eq!(from("SELECT a FROM t LEFT OUTER JOIN u ON x"),
+               join(JoinType::Left, table("t"), table("u"),
+                    Some(JoinCriteria::On(Expression::Identifier { name: "x".to_string() }))));
+    assert_eq!(from("SELECT a FROM t NATURAL RIGHT JOIN u"),
+               join(JoinType::Right, table("t"), table("u"), Some(JoinCriteria::Natural)));
+}
+
+#[test]
+fn testJoinPrecedence() {
+    assertStatement("SELECT a FROM t CROSS JOIN u LEFT JOIN v ON true");
+    assertStatement("SELECT a FROM t CROSS JOIN u NATURAL JOIN v CROSS JOIN w NATURAL JOIN x");
+    assertStatement("SELECT a FROM t JOIN u JOIN v ON x ON y");
+    assertStatement("SELECT a FROM (t JOIN u ON x) JOIN v ON y");
+
+    let on = |name: &str| Some(JoinCriteria::On(Expression::Identifier { name: name.to_string() }));
+    assert_eq!(from("SELECT a FROM t JOIN u ON x JOIN v ON y"),
+               join(JoinType::Inner, join(JoinType::Inner, table("t"), table("u"), on("x")), table("v"), on("y")));
+    assert_eq!(from("SELECT a FROM t JOIN u JOIN v ON x ON y"),
+               join(JoinType::Inner, table("t"), join(JoinType::Inner, table("u"), table("v"), on("x")), on("y")));
+    assert_eq!(from("SELECT a FROM t CROSS JOIN u LEFT JOIN v ON x"),
+               join(JoinType::Left, join(JoinType::Cross, table("t"), table("u"), None), table("v"), on("x")));
+}
+
+#[test]
+fn testUnnest() {
+    assertStatement("SELECT * FROM t CROSS JOIN UNNEST(a)");
+    assertStatement("SELECT * FROM t CROSS JOIN UNNEST(a, b) WITH ORDINALITY");
+    assertStatement("SELECT * FROM t CROSS JOIN UNNEST(arr) WITH ORDINALITY AS u(x, i)");
+    assertStatement("WITH ordinality AS (SELECT 1) SELECT * FROM ordinality");
+    assertStatement("WITH ordinality (x) AS (SELECT 1) SELECT * FROM ordinality");
+    assertStatement("SELECT * FROM UNNEST(ARRAY [1, 2]) x");
+    assertStatement("SELECT * FROM t LEFT JOIN UNNEST(m) u(k, v) ON true");
+    assertInvalidStatement("SELECT * FROM UNNEST()");
+    assertInvalidStatement("SELECT * FROM UNNEST(a) WITH");
+    assertStatement("SELECT unnest, unnest.a FROM unnest");
+    assertStatement("SELECT * FROM t CROSS JOIN unnest AS u");
+
+    assert_eq!(from("SELECT * FROM UNNEST(a) WITH ORDINALITY AS u (x, i)"),
+               Relation::Aliased {
+                   relation: Box::new(Relation::Unnest {
+                       expressions: vec![Expression::Identifier { name: "a".to_string() }],
+                       with_ordinality: true
+                   }),
+                   alias: "u".to_string(),
+                   columns: Some(vec![ColumnName { identifier: "x".to_string() }, ColumnName { identifier: "i".to_string() }])
+               });
+}
+
+#[test]
+fn testLateral() {
+    assertStatement("SELECT * FROM t, LATERAL (SELECT * FROM u WHERE u.a = t.a)");
+    assertStatement("SELECT * FROM t CROSS JOIN LATERAL (SELECT a) x (b)");
+    assertStatement("SELECT * FROM t JOIN LATERAL (VALUES 1) x ON true");
+    assertInvalidStatement("SELECT * FROM LATERAL (u)");
+    // without parentheses it is a table named lateral
+    assertStatement("SELECT * FROM LATERAL u");
+    assertStatement("SELECT lateral FROM t AS lateral");
+
+    assert_eq!(from("SELECT * FROM LATERAL (SELECT a)"),
+               Relation::Lateral(Box::new(parseStatement("SELECT a").unwrap())));
+}
+
+#[test]
+fn testTableSample() {
+    assertStatement("SELECT * FROM t TABLESAMPLE BERNOULLI (10)");
+    assertStatement("SELECT * FROM t x TABLESAMPLE SYSTEM (10 + 1)");
+    assertStatement("SELECT * FROM t TABLESAMPLE BERNOULLI (10) CROSS JOIN u TABLESAMPLE SYSTEM (5)");
+    assertStatement("SELECT * FROM (t TABLESAMPLE SYSTEM (5)) x");
+    assertInvalidStatement("SELECT * FROM t TABLESAMPLE (10)");
+    assertInvalidStatement("SELECT * FROM t TABLESAMPLE RANDOM (10)");
+    assertInvalidStatement("SELECT * FROM t TABLESAMPLE BERNOULLI (10) x");
+    assertStatement("SELECT tablesample FROM tablesample AS tablesample TABLESAMPLE SYSTEM (1)");
+    assertStatement("SELECT lateral, unnest, tablesample FROM t");
+
+    assert_eq!(from("SELECT * FROM t AS x TABLESAMPLE BERNOULLI (10)"),
+               Relation::Sampled {
+                   relation: Box::new(Relation::Aliased { relation: Box::new(table("t")), alias: "x".to_string(), columns: None }),
+                   sample_type: SampleType::Bernoulli,
+                   percentage: Box::new(Expression::Literal(Literal::Numeric("10".to_string())))
+               });
+}
+
+#[test]
+fn testGroupBy() {
+    assertStatement("SELECT a, count(b) FROM t GROUP BY a");
+    assertStatement("SELECT a, b, sum(c) FROM t WHERE c > 0 GROUP BY 1, 2");
+    assertStatement("SELECT a + b FROM t GROUP BY a + b, (a, b), ()");
+    assertStatement("SELECT a FROM t GROUP BY ALL a");
+    assertStatement("SELECT a, count(*) FROM t GROUP BY a HAVING count(*) > 1");
+    assertStatement("SELECT count(*) FROM t HAVING count(*) > 1");
+    assertInvalidStatement("SELECT a FROM t GROUP BY");
+    assertInvalidStatement("SELECT a FROM t HAVING");
+    assertInvalidStatement("SELECT a FROM t GROUP BY a WHERE a > 1");
+
+    let a = || Expression::Identifier { name: "a".to_string() };
+    let b = || Expression::Identifier { name: "b".to_string() };
+    let select = select("SELECT a FROM t GROUP BY DISTINCT a, (a, b) HAVING b");
+    assert_eq!(select.group_by, Some(GroupBy {
+        distinct: true,
+        elements: vec![GroupingElement::Simple(vec![a()]), GroupingElement::Simple(vec![a(), b()])]
+    }));
+    assert_eq!(select.having, Some(b()));
+}
+
+#[test]
+fn testGroupingSets() {
+    assertStatement("SELECT a FROM t GROUP BY GROUPING SETS (a, (a, b), ())");
+    assertStatement("SELECT a FROM t GROUP BY ROLLUP (a, b)");
+    assertStatement("SELECT a FROM t GROUP BY CUBE (a, b), c");
+    assertStatement("SELECT a FROM t GROUP BY a, ROLLUP (b, c), CUBE (d), GROUPING SETS ((e), (f, g))");
+    assertStatement("SELECT a FROM t GROUP BY CUBE ()");
+    assertInvalidStatement("SELECT a FROM t GROUP BY GROUPING SETS ()");
+    assertInvalidStatement("SELECT a FROM t GROUP BY ROLLUP a");
+
+    // CUBE, ROLLUP and GROUPING are non-reserved, only in GROUP BY and followed by `(` do they group
+    assertStatement("SELECT cube, rollup, grouping FROM t");
+    assertStatement("SELECT cube.rollup, grouping[1] FROM cube AS grouping");
+    assertStatement("SELECT a FROM t GROUP BY grouping, cube.a");
+    assertStatement("SELECT cube(a), rollup(a, b) FROM t");
+
+    let column = |name: &str| Expression::Identifier { name: name.to_string() };
+    assert_eq!(select("SELECT a FROM t GROUP BY cube, rollup").group_by,
+               Some(GroupBy {
+                   distinct: false,
+                   elements: vec![
+                       GroupingElement::Simple(vec![column("cube")]),
+                       GroupingElement::Simple(vec![column("rollup")])
+                   ]
+               }));
+    // quoted, qualified, used as an operand or with a quantifier they stay calls
+    for sql in ["\"cube\"(a)", "x.rollup(a)", "cube(a) + 1", "cube(DISTINCT a)"].iter() {
+        let group_by = select(&("SELECT a FROM t GROUP BY ".to_string() + sql)).group_by.unwrap();
+        assert!(matches!(group_by.elements.as_slice(), [GroupingElement::Simple(_)]), "{}", sql);
+    }
+    assert_eq!(select("SELECT a FROM t GROUP BY GROUPING SETS (a, (a, b)), ROLLUP (c), CUBE (d)").group_by,
+               Some(GroupBy {
+                   distinct: false,
+                   elements: vec![
+                       GroupingElement::GroupingSets(vec![vec![column("a")], vec![column("a"), column("b")]]),
+                       GroupingElement::Rollup(vec![column("c")]),
+                       GroupingElement::Cube(vec![column("d")])
+                   ]
+               }));
+}
+
+#[test]
+fn testGrouping() {
+    assertExpression("grouping(a)");
+    assertExpression("GROUPING(a, t.b)");
+    assertStatement("SELECT a, b, GROUPING(a, b) FROM t GROUP BY GROUPING SETS ((a), (b))");
+    assertInvalidExpression("grouping(a + 1)");
+
+    assert_eq!(parseExpression("GROUPING(a, t.b)").unwrap(),
+               Expression::Grouping(vec![
+                   QualifiedName { name: vec!["a".to_string()] },
+                   QualifiedName { name: vec!["t".to_string(), "b".to_string()] }
+               ]));
+}
+
+#[test]
+fn testUse() {
+    assertStatement("USE foo");
+    assertStatement("USE foo.bar");
+    assertInvalidStatement("USE");
+}
+
+#[test]
+fn testCreateSchema() {
+    assertStatement("CREATE SCHEMA test");
+    assertStatement("CREATE SCHEMA IF NOT EXISTS test");
+    assertStatement("CREATE SCHEMA catalog.test");
+    assertInvalidStatement("CREATE SCHEMA IF EXISTS test");
+}
+
+#[test]
+fn testDropSchema() {
+    assertStatement("DROP SCHEMA test");
+    assertStatement("DROP SCHEMA test CASCADE");
+    assertStatement("DROP SCHEMA IF EXISTS test");
+    assertStatement("DROP SCHEMA IF EXISTS test RESTRICT");
+    assertStatement("DROP SCHEMA catalog.test");
+    assertInvalidStatement("DROP SCHEMA IF NOT EXISTS test");
+}
+
+#[test]
+fn testRenameSchema() {
+    assertStatement("ALTER SCHEMA foo RENAME TO bar");
+    assertStatement("ALTER SCHEMA foo.bar RENAME TO baz");
+    assertInvalidStatement("ALTER SCHEMA foo RENAME TO bar.baz");
+}
+
+#[test]
+fn testCreateTable() {
+    assertStatement("CREATE TABLE foo (a VARCHAR, b BIGINT)");
+    assertStatement("CREATE TABLE IF NOT EXISTS bar (c DATE)");
+    assertStatement("CREATE TABLE a.b.c (d map<varchar, bigint>, e array<varchar(10)>)");
+    assertStatement("CREATE TABLE t (a bigint)");
+    assertStatement("CREATE TABLE IF NOT EXISTS bar (c TIMESTAMP)");
+    assertStatement("CREATE TABLE a.b.c (d map<varchar, bigint>, e array<double>)");
+    assertStatement("CREATE TABLE t (year bigint, day interval day to second)");
+    assertStatement("CREATE TABLE t (r row(x bigint, y array<varchar>))");
+    assertInvalidStatement("CREATE TABLE t ()");
+}
+
+#[test]
+fn testCreateTableColumnOptions() {
+    assertStatement("CREATE TABLE foo (a VARCHAR NOT NULL, b BIGINT DEFAULT 1 + 2)");
+    assertStatement("CREATE TABLE foo (a VARCHAR NOT NULL DEFAULT 'x' COMMENT 'column a')");
+    assertStatement("CREATE TABLE foo (a VARCHAR WITH (nullable = true), b BIGINT COMMENT 'b' WITH (x = 'y'))");
+    assertStatement("CREATE TABLE foo (a time with time zone, b timestamp NOT NULL, c time(3) WITH (x = 1))");
+    assertStatement("CREATE TABLE foo (a timestamp WITH (x = 1), b time with time zone WITH (y = 2))");
+    assertInvalidStatement("CREATE TABLE foo (a bigint with time zone)");
+    assertStatement("CREATE TABLE foo (a bigint NOT NULL COMMENT 'x' DEFAULT 1, b bigint DEFAULT 1 NOT NULL)");
+    assertStatement("CREATE TABLE foo (a VARCHAR COMMENT 'a' NOT NULL, b boolean DEFAULT (1 = 1))");
+    assertInvalidStatement("CREATE TABLE foo (a bigint NOT NULL DEFAULT 1 NOT NULL)");
+    assertInvalidStatement("CREATE TABLE foo (a bigint COMMENT 'a' COMMENT 'b')");
+    assertInvalidStatement("CREATE TABLE foo (a bigint DEFAULT 1 DEFAULT 2)");
+    assert!(matches!(parseStatement("CREATE TABLE foo (a bigint COMMENT 'a' NOT NULL COMMENT 'b')"),
+                     Err(ParseError::User { error: token::Error { code: ErrorCode::DuplicateColumnOption, .. } })));
+    assertInvalidStatement("CREATE TABLE foo (a VARCHAR DEFAULT)");
+
+    match parseStatement("CREATE TABLE foo (a VARCHAR NOT NULL DEFAULT 'x' COMMENT 'column a' WITH (p = 1))").unwrap() {
+        Statement::CreateTable { table_elements, .. } => {
+            assert_eq!(table_elements, vec![TableElement::ColumnDefinition(ColumnDefinition {
+                name: "a".to_string(),
+                column_type: Type::User_Defined("VARCHAR".to_string(), None),
+                not_null: true,
+                default: Some(Expression::Literal(Literal::String("x".to_string()))),
+                comment: Some("column a".to_string()),
+                properties: vec![Property {
+                    name: "p".to_string(),
+                    value: Expression::Literal(Literal::Numeric("1".to_string()))
+                }]
+            })]);
+        }
+        other => panic!("Expected CreateTable but found {:?}", other)
+    }
+
+    match parseStatement("CREATE TABLE foo (a time with time zone, b time)").unwrap() {
+        Statement::CreateTable { table_elements, .. } => {
+            let types: Vec<Type> = table_elements.into_iter().map(|element| match element {
+                TableElement::ColumnDefinition(column) => column.column_type,
+                other => panic!("Expected ColumnDefinition but found {:?}", other)
+            }).collect();
+            assert_eq!(types, vec![Type::TIME_WITH_TIME_ZONE(None), Type::User_Defined("time".to_string(), None)]);
+        }
+        other => panic!("Expected CreateTable but found {:?}", other)
+    }
+}
+
+#[test]
+fn testCreateTableConstraints() {
+    assertStatement("CREATE TABLE foo (a bigint, b bigint, PRIMARY KEY (a), UNIQUE (a, b), CHECK (a > b))");
+    assertStatement("CREATE TABLE foo (LIKE bar, c bigint)");
+    assertStatement("CREATE TABLE foo (LIKE a.bar INCLUDING PROPERTIES, LIKE baz EXCLUDING PROPERTIES)");
+    assertInvalidStatement("CREATE TABLE foo (a bigint, PRIMARY KEY ())");
+    assertInvalidStatement("CREATE TABLE foo (LIKE bar INCLUDING)");
+
+    match parseStatement("CREATE TABLE foo (LIKE bar INCLUDING PROPERTIES, PRIMARY KEY (a, b), CHECK (a))").unwrap() {
+        Statement::CreateTable { table_elements, .. } => {
+            assert_eq!(table_elements, vec![
+                TableElement::Like {
+                    table_name: QualifiedName { name: vec!["bar".to_string()] },
+                    properties: Some(LikeProperties::Including)
+                },
+                TableElement::Constraint(TableConstraint::PrimaryKey(vec![
+                    ColumnName { identifier: "a".to_string() }, ColumnName { identifier: "b".to_string() }
+                ])),
+                TableElement::Constraint(TableConstraint::Check(Expression::Identifier { name: "a".to_string() }))
+            ]);
+        }
+        other => panic!("Expected CreateTable but found {:?}", other)
+    }
+}
+
+#[test]
+fn testCreateTableProperties() {
+    assertStatement("CREATE TABLE foo (a bigint) COMMENT 'table'");
+    assertStatement("CREATE TABLE foo (a bigint) WITH (format = 'CSV', partitioned_by = ARRAY['ds'])");
+    assertStatement("CREATE TABLE foo (a bigint) COMMENT 'table' WITH (format = 'CSV')");
+    assertInvalidStatement("CREATE TABLE foo (a bigint) WITH ()");
+    assertInvalidStatement("CREATE TABLE foo (a bigint) WITH (format 'CSV')");
+
+    match parseStatement("CREATE TABLE foo (a bigint) COMMENT 'table' WITH (format = 'CSV')").unwrap() {
+        Statement::CreateTable { comment, properties, .. } => {
+            assert_eq!(comment, Some("table".to_string()));
+            assert_eq!(properties, vec![Property {
+                name: "format".to_string(),
+                value: Expression::Literal(Literal::String("CSV".to_string()))
+            }]);
+        }
+        other => panic!("Expected CreateTable but found {:?}", other)
+    }
+}
+
+#[test]
+fn testCreateTableAsSelect() {
+    assertStatement("CREATE TABLE foo AS SELECT a FROM t");
+    assertStatement("CREATE TABLE foo AS (SELECT a FROM t)");
+    assertStatement("CREATE TABLE IF NOT EXISTS foo AS SELECT a FROM t");
+    assertStatement("CREATE TABLE foo (x) AS SELECT a FROM t");
+    assertStatement("CREATE TABLE foo (x, y) AS (SELECT a, b FROM t)");
+    assertStatement("CREATE TABLE foo COMMENT 'x' WITH (format = 'ORC') AS SELECT a FROM t");
+    assertStatement("CREATE TABLE foo AS SELECT a FROM t WITH DATA");
+    assertStatement("CREATE TABLE foo AS SELECT a FROM t WITH NO DATA");
+    assertStatement("CREATE TABLE foo AS SELECT * FROM unnest(a) WITH ORDINALITY");
+    assertStatement("CREATE TABLE foo AS SELECT * FROM unnest(a) WITH ORDINALITY WITH DATA");
+    assertStatement("CREATE TABLE foo AS SELECT * FROM t CROSS JOIN unnest(a) WITH ORDINALITY WITH DATA");
+    assertStatement("CREATE TABLE foo AS SELECT * FROM unnest(a) u WITH NO DATA");
+    assertStatement("CREATE TABLE foo AS SELECT * FROM unnest(a) WHERE true WITH NO DATA");
+    assertStatement("CREATE TABLE foo AS SELECT * FROM unnest(a) ORDER BY 1 WITH NO DATA");
+    assertStatement("CREATE TABLE foo AS SELECT 1 UNION SELECT * FROM unnest(a) u WITH DATA");
+    assertStatement("CREATE TABLE foo AS (SELECT * FROM unnest(a)) WITH NO DATA");
+    assertStatement("CREATE TABLE foo AS VALUES 1, 2 WITH DATA");
+    assertStatement("CREATE TABLE foo AS SELECT 1 WITH DATA");
+    assertStatement("CREATE TABLE foo AS WITH t AS (SELECT 1) SELECT * FROM t WITH DATA");
+    assertStatement("CREATE TABLE foo AS WITH data AS (SELECT 1) SELECT * FROM data WITH NO DATA");
+    assertStatement("CREATE TABLE foo AS WITH data (a) AS (SELECT 1) SELECT * FROM unnest(a) WITH DATA");
+    assertStatement("CREATE TABLE foo AS SELECT * FROM unnest(a) WITH NO DATA");
+    assertInvalidStatement("CREATE TABLE foo (x bigint) AS SELECT a FROM t");
+    assertInvalidStatement("CREATE TABLE foo AS SELECT a FROM t WITH");
+
+    match parseStatement("CREATE TABLE foo AS SELECT a FROM t WITH NO DATA").unwrap() {
+        Statement::CreateTableAsSelect { with_data, .. } => {
+            assert_eq!(with_data, Some(false));
+        }
+        other => panic!("Expected CreateTableAsSelect but found {:?}", other)
+    }
+
+    match parseStatement("CREATE TABLE foo (x, y) AS SELECT a, b FROM t").unwrap() {
+        Statement::CreateTableAsSelect { columns, .. } => {
+            assert_eq!(columns.unwrap().len(), 2);
+        }
+        other => panic!("Expected CreateTableAsSelect but found {:?}", other)
+    }
+}
+
+#[test]
+fn testDropTable() {
+    assertStatement("DROP TABLE a");
+    assertStatement("DROP TABLE a.b");
+    assertStatement("DROP TABLE a.b.c");
+    assertStatement("DROP TABLE IF EXISTS a");
+    assertStatement("DROP TABLE IF EXISTS a.b.c");
+    assertInvalidStatement("DROP TABLE");
+}
+
+#[test]
+fn testRenameTable() {
+    assertStatement("ALTER TABLE a RENAME TO b");
+    assertStatement("ALTER TABLE IF EXISTS a.b RENAME TO c.d");
+    assertInvalidStatement("ALTER TABLE a RENAME b");
+
+    match parseStatement("ALTER TABLE IF EXISTS a RENAME TO b.c").unwrap() {
+        Statement::RenameTable { from, to, if_exists } => {
+            assert_eq!(from, QualifiedName { name: vec!["a".to_string()] });
+            assert_eq!(to, QualifiedName { name: vec!["b".to_string(), "c".to_string()] });
+            assert!(if_exists);
+        }
+        other => panic!("Expected RenameTable but found {:?}", other)
+    }
+}
+
+#[test]
+fn testAddColumn() {
+    assertStatement("ALTER TABLE foo.t ADD COLUMN c bigint");
+    assertStatement("ALTER TABLE IF EXISTS foo.t ADD COLUMN IF NOT EXISTS c bigint NOT NULL COMMENT 'c'");
+    assertStatement("ALTER TABLE foo.t ADD COLUMN d double WITH (x = 1)");
+    assertStatement("ALTER TABLE t ADD COLUMN c timestamp WITH (x = 1)");
+    assertInvalidStatement("ALTER TABLE foo.t ADD COLUMN c");
+
+    match parseStatement("ALTER TABLE t ADD COLUMN IF NOT EXISTS c varchar DEFAULT 'x'").unwrap() {
+        Statement::AddColumn { if_exists, column_if_not_exists, column, .. } => {
+            assert!(!if_exists);
+            assert!(column_if_not_exists);
+            assert_eq!(column.name, "c");
+            assert_eq!(column.default, Some(Expression::Literal(Literal::String("x".to_string()))));
+        }
+        other => panic!("Expected AddColumn but found {:?}", other)
+    }
+}
+
+#[test]
+fn testDropColumn() {
+    assertStatement("ALTER TABLE foo.t DROP COLUMN c");
+    assertStatement("ALTER TABLE \"t x\" DROP COLUMN \"c d\"");
+    assertStatement("ALTER TABLE IF EXISTS foo.t DROP COLUMN IF EXISTS c");
+    assertInvalidStatement("ALTER TABLE foo.t DROP COLUMN");
+
+    match parseStatement("ALTER TABLE t DROP COLUMN IF EXISTS c").unwrap() {
+        Statement::DropColumn { if_exists, column_if_exists, column, .. } => {
+            assert!(!if_exists);
+            assert!(column_if_exists);
+            assert_eq!(column, "c");
+        }
+        other => panic!("Expected DropColumn but found {:?}", other)
+    }
+}
+
+#[test]
+fn testRenameColumn() {
+    assertStatement("ALTER TABLE foo.t RENAME COLUMN a TO b");
+    assertStatement("ALTER TABLE IF EXISTS foo.t RENAME COLUMN IF EXISTS a TO b");
+    assertInvalidStatement("ALTER TABLE foo.t RENAME COLUMN a b");
+
+    match parseStatement("ALTER TABLE t RENAME COLUMN a TO b").unwrap() {
+        Statement::RenameColumn { from, to, column_if_exists, .. } => {
+            assert_eq!(from, "a");
+            assert_eq!(to, "b");
+            assert!(!column_if_exists);
+        }
+        other => panic!("Expected RenameColumn but found {:?}", other)
+    }
+}
+
+#[test]
+fn testAlterColumnSetDataType() {
+    assertStatement("ALTER TABLE foo.t ALTER COLUMN a SET DATA TYPE bigint");
+    assertStatement("ALTER TABLE IF EXISTS foo.t ALTER COLUMN a SET DATA TYPE timestamp with time zone");
+    assertInvalidStatement("ALTER TABLE foo.t ALTER COLUMN a SET TYPE bigint");
+
+    match parseStatement("ALTER TABLE t ALTER COLUMN a SET DATA TYPE array<varchar>").unwrap() {
+        Statement::SetColumnType { column, column_type, .. } => {
+            assert_eq!(column, "a");
+            assert_eq!(column_type, Type::Array(Box::new(Type::User_Defined("varchar".to_string(), None))));
+        }
+        other => panic!("Expected SetColumnType but found {:?}", other)
+    }
+}
+
+#[test]
+fn testSetTableProperties() {
+    assertStatement("ALTER TABLE a SET PROPERTIES foo = 'bar'");
+    assertStatement("ALTER TABLE a.b SET PROPERTIES foo = true, bar = ARRAY['ds']");
+    assertInvalidStatement("ALTER TABLE a SET PROPERTIES");
+    assertInvalidStatement("ALTER TABLE a SET PROPERTIES (foo = 'bar')");
+
+    match parseStatement("ALTER TABLE a SET PROPERTIES foo = 1").unwrap() {
+        Statement::SetTableProperties { properties, .. } => {
+            assert_eq!(properties, vec![Property {
+                name: "foo".to_string(),
+                value: Expression::Literal(Literal::Numeric("1".to_string()))
+            }]);
+        }
+        other => panic!("Expected SetTableProperties but found {:?}", other)
+    }
+}
+
+#[test]
+fn testCreateView() {
+    assertStatement("CREATE VIEW a AS SELECT * FROM t");
+    assertStatement("CREATE OR REPLACE VIEW a AS SELECT * FROM t");
+    assertStatement("CREATE VIEW a.b.c (x, y) AS SELECT a, b FROM t");
+    assertStatement("CREATE VIEW a AS WITH w AS (SELECT 1) SELECT * FROM w");
+    assertInvalidStatement("CREATE VIEW a");
+    assertInvalidStatement("CREATE REPLACE VIEW a AS SELECT * FROM t");
+
+    assert_eq!(parseStatement("CREATE OR REPLACE VIEW v (x) AS SELECT a FROM t").unwrap(), Statement::CreateView {
+        view_name: QualifiedName { name: vec!["v".to_string()] },
+        or_replace: true,
+        columns: Some(vec![ColumnName { identifier: "x".to_string() }]),
+        query: Box::new(parseStatement("SELECT a FROM t").unwrap())
+    });
+}
+
+#[test]
+fn testDropView() {
+    assertStatement("DROP VIEW a");
+    assertStatement("DROP VIEW a.b.c");
+    assertStatement("DROP VIEW IF EXISTS a.b");
+    assertInvalidStatement("DROP VIEW");
+}
+
+#[test]
+fn testRenameView() {
+    assertStatement("ALTER VIEW a RENAME TO b");
+    assertStatement("ALTER VIEW a.b RENAME TO c.d");
+
+    assert_eq!(parseStatement("ALTER VIEW a RENAME TO b.c").unwrap(), Statement::AlterView {
+        from: QualifiedName { name: vec!["a".to_string()] },
+        to: QualifiedName { name: vec!["b".to_string(), "c".to_string()] }
+    });
+}
+
+#[test]
+fn testMaterializedView() {
+    assertStatement("CREATE MATERIALIZED VIEW a AS SELECT * FROM t");
+    assertStatement("CREATE OR REPLACE MATERIALIZED VIEW a.b AS SELECT * FROM t");
+    assertStatement("CREATE MATERIALIZED VIEW IF NOT EXISTS a AS SELECT * FROM t");
+    assertStatement("REFRESH MATERIALIZED VIEW a.b");
+    assertInvalidStatement("CREATE MATERIALIZED VIEW a (x) AS SELECT * FROM t");
+    assertInvalidStatement("REFRESH VIEW a");
+
+    assert_eq!(parseStatement("REFRESH MATERIALIZED VIEW a").unwrap(), Statement::RefreshMaterializedView {
+        view_name: QualifiedName { name: vec!["a".to_string()] }
+    });
+}
+
+#[test]
+fn testInsertInto() {
+    assertStatement("INSERT INTO a SELECT b FROM c");
+    assertStatement("INSERT INTO a (c1, c2) SELECT b1, b2 FROM c");
+    assertStatement("INSERT INTO a.b.c SELECT b FROM c");
+    assertStatement("INSERT INTO t VALUES (1, 'a'), (2, 'b')");
+    assertStatement("INSERT INTO t (c1) VALUES 1, 2");
+    assertInvalidStatement("INSERT a SELECT b FROM c");
+    assertInvalidStatement("INSERT INTO t VALUES");
+}
+
+#[test]
+fn testDelete() {
+    assertStatement("DELETE FROM t");
+    assertStatement("DELETE FROM \"awesome table\"");
+    assertStatement("DELETE FROM t WHERE a");
+    assertInvalidStatement("DELETE t");
+
+    assert_eq!(parseStatement("DELETE FROM a.b WHERE c").unwrap(), Statement::Delete {
+        from: QualifiedName { name: vec!["a".to_string(), "b".to_string()] },
+        filter: Some(Expression::Identifier { name: "c".to_string() })
+    });
+}
+
+#[test]
+fn testExplain() {
+    assertStatement("EXPLAIN SELECT * FROM t");
+    assertStatement("EXPLAIN (TYPE LOGICAL) SELECT * FROM t");
+    assertStatement("EXPLAIN (TYPE LOGICAL, FORMAT TEXT) SELECT * FROM t");
+    assertStatement("EXPLAIN (FORMAT GRAPHVIZ, TYPE DISTRIBUTED) SELECT * FROM t");
+    assertStatement("EXPLAIN (TYPE VALIDATE) DELETE FROM t");
+    assertStatement("EXPLAIN (TYPE IO, FORMAT JSON) INSERT INTO t SELECT * FROM u");
+    assertStatement("EXPLAIN (SELECT a FROM t) UNION SELECT b FROM u");
+    assertInvalidStatement("EXPLAIN");
+    assertInvalidStatement("EXPLAIN () SELECT * FROM t");
+    assertInvalidStatement("EXPLAIN (TYPE TEXT) SELECT * FROM t");
+
+    assert_eq!(parseStatement("EXPLAIN (TYPE DISTRIBUTED, FORMAT JSON) SELECT a FROM t").unwrap(), Statement::Explain {
+        analyze: false,
+        verbose: false,
+        options: vec![ExplainOption::Type(ExplainType::Distributed), ExplainOption::Format(ExplainFormat::Json)],
+        statement: Box::new(parseStatement("SELECT a FROM t").unwrap())
+    });
+}
+
+#[test]
+fn testExplainAnalyze() {
+    assertStatement("EXPLAIN ANALYZE SELECT * FROM t");
+    assertStatement("EXPLAIN ANALYZE VERBOSE SELECT * FROM t");
+    assertStatement("EXPLAIN VERBOSE (FORMAT TEXT) SELECT * FROM t");
+    assertStatement("EXPLAIN ANALYZE (TYPE DISTRIBUTED) SELECT * FROM t");
+    assertInvalidStatement("EXPLAIN VERBOSE ANALYZE SELECT * FROM t");
+
+    match parseStatement("EXPLAIN ANALYZE VERBOSE SELECT * FROM t").unwrap() {
+        Statement::Explain { analyze, verbose, options, .. } => {
+            assert!(analyze);
+            assert!(verbose);
+            assert!(options.is_empty());
+        }
+        other => panic!("Expected Explain but found {:?}", other)
+    }
+}
+
+#[test]
+fn testShowCatalogs() {
+    assertStatement("SHOW CATALOGS");
+    assertStatement("SHOW CATALOGS LIKE '%'");
+    assertStatement("SHOW CATALOGS LIKE '%$_%' ESCAPE '$'");
+    assertInvalidStatement("SHOW CATALOGS ESCAPE '$'");
+
+    assert_eq!(parseStatement("SHOW CATALOGS LIKE '%$_%' ESCAPE '$'").unwrap(), Statement::ShowCatalogs {
+        like: Some(LikePattern { pattern: "%$_%".to_string(), escape: Some("$".to_string()) })
+    });
+}
+
+#[test]
+fn testShowSchemas() {
+    assertStatement("SHOW SCHEMAS");
+    assertStatement("SHOW SCHEMAS FROM foo");
+    assertStatement("SHOW SCHEMAS IN foo LIKE '%'");
+    assertStatement("SHOW SCHEMAS IN foo LIKE '%$_%' ESCAPE '$'");
+    assertInvalidStatement("SHOW SCHEMAS LIKE a");
+
+    assert_eq!(parseStatement("SHOW SCHEMAS IN foo LIKE 't%'").unwrap(), Statement::ShowSchemas {
+        catalog: Some(QualifiedName { name: vec!["foo".to_string()] }),
+        like: Some(LikePattern { pattern: "t%".to_string(), escape: None })
+    });
+}
+
+#[test]
+fn testShowTables() {
+    assertStatement("SHOW TABLES");
+    assertStatement("SHOW TABLES FROM a");
+    assertStatement("SHOW TABLES FROM \"awesome schema\"");
+    assertStatement("SHOW TABLES IN a LIKE '%$_%' ESCAPE '$'");
+    assertStatement("SHOW TABLES LIKE 't%'");
+
+    assert_eq!(parseStatement("SHOW TABLES FROM a.b").unwrap(), Statement::ShowTables {
+        schema: Some(QualifiedName { name: vec!["a".to_string(), "b".to_string()] }),
+        like: None
+    });
+}
+
+#[test]
+fn testShowColumns() {
+    assertStatement("SHOW COLUMNS FROM a");
+    assertStatement("SHOW COLUMNS FROM a.b");
+    assertStatement("SHOW COLUMNS IN a.b LIKE 'c%'");
+    assertStatement("DESCRIBE a.b");
+    assertInvalidStatement("SHOW COLUMNS");
+    assertInvalidStatement("DESCRIBE");
+
+    assert_eq!(parseStatement("DESCRIBE a.b").unwrap(), Statement::Describe {
+        table_name: QualifiedName { name: vec!["a".to_string(), "b".to_string()] }
+    });
+}
+
+#[test]
+fn testShowFunctions() {
+    assertStatement("SHOW FUNCTIONS");
+    assertStatement("SHOW FUNCTIONS LIKE 'array%'");
+    assertStatement("SHOW SESSION");
+    assertStatement("SHOW SESSION LIKE 'query%' ESCAPE '\\'");
+}
+
+#[test]
+fn testShowCreate() {
+    assertStatement("SHOW CREATE TABLE a.b");
+    assertStatement("SHOW CREATE VIEW v");
+    assertStatement("SHOW CREATE MATERIALIZED VIEW a.m");
+    assertInvalidStatement("SHOW CREATE SCHEMA a");
+
+    assert_eq!(parseStatement("SHOW CREATE MATERIALIZED VIEW m").unwrap(), Statement::ShowCreate {
+        object_type: ShowCreateType::MaterializedView,
+        name: QualifiedName { name: vec!["m".to_string()] }
+    });
+}
+
+#[test]
+fn testShowStats() {
+    assertStatement("SHOW STATS FOR t");
+    assertStatement("SHOW STATS FOR a.b.c");
+    assertInvalidStatement("SHOW STATS t");
+}
+
+#[test]
+fn testParameter() {
+    assertExpression("?");
+    assertExpression("a = ? AND b > :b");
+    assertExpression("a = ?2 AND b > ?1");
+    assertExpression(":name + $x - @y");
+    assertInvalidExpression("a = $");
+
+    let parameter = |position: usize, name: &str| Expression::Parameter { position, name: name.to_string() };
+    assert_eq!(parseExpression("?").unwrap(), parameter(0, "?"));
+    assert_eq!(parseExpression("f(?, :a, :b)[?]").unwrap(), Expression::Subscript {
+        base: Box::new(Expression::FunctionCall(FunctionCall {
+            name: QualifiedName { name: vec!["f".to_string()] },
+            distinct: false,
+            all_rows: false,
+            arguments: vec![
+                FunctionArgument::Positional(parameter(0, "?")),
+                FunctionArgument::Positional(parameter(1, ":a")),
+                FunctionArgument::Positional(parameter(2, ":b"))
+            ],
+            order_by: None,
+            filter: None,
+            window: None
+        })),
+        index: Box::new(parameter(3, "?"))
+    });
+
+    assert_eq!(parseExpression("?2 + ?1").unwrap(),
+               BooleanExpression::binary(parameter(1, "?2"), BinaryOperator::Add, parameter(0, "?1")));
+    let sum = BooleanExpression::binary(parameter(0, ":a"), BinaryOperator::Add, parameter(1, "?"));
+    assert_eq!(parseExpression(":a + ? + :a").unwrap(),
+               BooleanExpression::binary(sum, BinaryOperator::Add, parameter(0, ":a")));
+    assertInvalidExpression("?0");
+
+    // numbered parameters cannot be mixed with bare or named ones, which would take their positions
+    assert!(matches!(parseStatement("SELECT :a, ?1 FROM t"),
+                     Err(ParseError::User { error: token::Error { code: ErrorCode::MixedParameters, .. } })));
+    assertInvalidStatement("SELECT ?1, ? FROM t");
+    assertInvalidExpression("a = ? AND b > ?1");
+
+    // positions follow the text, whatever the nesting
+    match parseStatement("SELECT ? FROM t WHERE a IN (SELECT ? FROM u) AND b = ? LIMIT ?").unwrap().bind(&[]) {
+        Err(BindError::ParameterCount { expected, given }) => {
+            assert_eq!(expected, 4);
+            assert_eq!(given, 0);
+        }
+        other => panic!("Expected a parameter count error but found {:?}", other)
+    }
+}
+
+#[test]
+fn testBind() {
+    let number = |value: &str| Expression::Literal(Literal::Numeric(value.to_string()));
+    let bound = |sql: &str, values: &[&str]| {
+        let values: Vec<Expression> = values.iter().map(|value| parseExpression(value).unwrap()).collect();
+        parseStatement(sql).unwrap().bind(&values)
+    };
+
+    assert_eq!(bound("SELECT a FROM t WHERE b = ? AND c > ?", &["1", "'x'"]).unwrap(),
+               parseStatement("SELECT a FROM t WHERE b = 1 AND c > 'x'").unwrap());
+    assert_eq!(bound("SELECT ? FROM t WHERE a IN (SELECT b FROM u WHERE c = ?) ORDER BY 1 LIMIT ?",
+                     &["x", "2", "10"]).unwrap(),
+               parseStatement("SELECT x FROM t WHERE a IN (SELECT b FROM u WHERE c = 2) ORDER BY 1 LIMIT 10").unwrap());
+    assert_eq!(bound("WITH w AS (SELECT ? AS a) SELECT * FROM w JOIN u ON w.a = u.a + ?", &["1", "2"]).unwrap(),
+               parseStatement("WITH w AS (SELECT 1 AS a) SELECT * FROM w JOIN u ON w.a = u.a + 2").unwrap());
+    assert_eq!(bound("INSERT INTO t VALUES (?, ?)", &["1", "2"]).unwrap(),
+               parseStatement("INSERT INTO t VALUES (1, 2)").unwrap());
+    assert_eq!(bound("UPDATE t SET a = :a WHERE b = :b", &["1", "2"]).unwrap(),
+               parseStatement("UPDATE t SET a = 1 WHERE b = 2").unwrap());
+    assert_eq!(bound("SELECT transform(a, x -> x + ?) FROM t", &["1"]).unwrap(),
+               parseStatement("SELECT transform(a, x -> x + 1) FROM t").unwrap());
+    assert_eq!(bound("SELECT a FROM t", &[]).unwrap(), parseStatement("SELECT a FROM t").unwrap());
+
+    match parseStatement("PREPARE p FROM DELETE FROM t WHERE a = ?").unwrap() {
+        Statement::Prepare { statement, .. } => {
+            assert_eq!(statement.bind(&[number("3")]).unwrap(), parseStatement("DELETE FROM t WHERE a = 3").unwrap());
+        }
+        other => panic!("Expected Prepare but found {:?}", other)
+    }
+
+    assert_eq!(bound("SELECT ?2, ?1", &["1", "2"]).unwrap(), parseStatement("SELECT 2, 1").unwrap());
+    assert_eq!(bound("SELECT ?1, ?1", &["1"]).unwrap(), parseStatement("SELECT 1, 1").unwrap());
+    assert_eq!(bound("SELECT a FROM t WHERE b = :x OR c = :x", &["1"]).unwrap(),
+               parseStatement("SELECT a FROM t WHERE b = 1 OR c = 1").unwrap());
+    assert_eq!(bound("SELECT ?1, ?1", &["1", "2"]), Err(BindError::ParameterCount { expected: 1, given: 2 }));
+    assert_eq!(bound("SELECT ? + ?", &["1"]), Err(BindError::ParameterCount { expected: 2, given: 1 }));
+    assert_eq!(bound("SELECT ?", &["1", "2"]), Err(BindError::ParameterCount { expected: 1, given: 2 }));
+}
+
+#[test]
+fn testPrepare() {
+    assertStatement("PREPARE myquery FROM SELECT * FROM foo");
+    assertStatement("PREPARE myquery FROM SELECT ?, ? FROM foo");
+    assertStatement("PREPARE myquery FROM INSERT INTO t VALUES (?)");
+    assertInvalidStatement("PREPARE myquery SELECT * FROM foo");
+
+    assert_eq!(parseStatement("PREPARE p FROM SELECT a FROM t").unwrap(), Statement::Prepare {
+        name: "p".to_string(),
+        statement: Box::new(parseStatement("SELECT a FROM t").unwrap())
+    });
+}
+
+#[test]
+fn testExecute() {
+    assertStatement("EXECUTE myquery");
+    assertStatement("EXECUTE myquery USING 1, 'abc', ARRAY ['hello']");
+    assertInvalidStatement("EXECUTE myquery USING");
+
+    assert_eq!(parseStatement("EXECUTE p USING 1, a").unwrap(), Statement::Execute {
+        name: "p".to_string(),
+        parameters: vec![Expression::Literal(Literal::Numeric("1".to_string())), Expression::Identifier { name: "a".to_string() }]
+    });
+}
+
+#[test]
+fn testDeallocatePrepare() {
+    assertStatement("DEALLOCATE PREPARE myquery");
+    assertInvalidStatement("DEALLOCATE myquery");
+
+    assert_eq!(parseStatement("DEALLOCATE PREPARE p").unwrap(), Statement::Deallocate { name: "p".to_string() });
+}
+
+#[test]
+fn testDescribeInputOutput() {
+    assertStatement("DESCRIBE INPUT myquery");
+    assertStatement("DESCRIBE OUTPUT myquery");
+    assertStatement("DESCRIBE input");
+
+    assert_eq!(parseStatement("DESCRIBE INPUT p").unwrap(), Statement::DescribeInput { name: "p".to_string() });
+    assert_eq!(parseStatement("DESCRIBE OUTPUT p").unwrap(), Statement::DescribeOutput { name: "p".to_string() });
+    assert_eq!(parseStatement("DESCRIBE output").unwrap(), Statement::Describe {
+        table_name: QualifiedName { name: vec!["output".to_string()] }
+    });
+}
+
+#[test]
+fn testStartTransaction() {
+    assertStatement("START TRANSACTION");
+    assertStatement("START TRANSACTION ISOLATION LEVEL READ UNCOMMITTED");
+    assertStatement("START TRANSACTION ISOLATION LEVEL READ COMMITTED");
+    assertStatement("START TRANSACTION ISOLATION LEVEL REPEATABLE READ");
+    assertStatement("START TRANSACTION ISOLATION LEVEL SERIALIZABLE");
+    assertStatement("START TRANSACTION READ ONLY");
+    assertStatement("START TRANSACTION READ WRITE");
+    assertStatement("START TRANSACTION ISOLATION LEVEL READ COMMITTED, READ ONLY");
+    assertStatement("START TRANSACTION READ ONLY, ISOLATION LEVEL READ COMMITTED");
+    assertInvalidStatement("START TRANSACTION ISOLATION LEVEL READ");
+    assertInvalidStatement("START TRANSACTION READ ONLY READ WRITE");
+
+    assert_eq!(parseStatement("START TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ WRITE").unwrap(),
+               Statement::StartTransaction {
+                   modes: vec![TransactionMode::Isolation(IsolationLevel::RepeatableRead), TransactionMode::ReadWrite]
+               });
+}
+
+#[test]
+fn testTransactionControl() {
+    assertStatement("BEGIN");
+    assertStatement("COMMIT");
+    assertStatement("COMMIT WORK");
+    assertStatement("ROLLBACK");
+    assertStatement("ROLLBACK WORK");
+    assertInvalidStatement("BEGIN COMMIT");
+
+    assert_eq!(parseStatement("BEGIN").unwrap(), Statement::Begin);
+    assert_eq!(parseStatement("COMMIT WORK").unwrap(), Statement::Commit);
+    assert_eq!(parseStatement("ROLLBACK WORK").unwrap(), Statement::Rollback);
+}
+
+#[test]
+fn testSavepoint() {
+    assertStatement("SAVEPOINT s1");
+    assertStatement("RELEASE SAVEPOINT s1");
+    assertStatement("ROLLBACK TO SAVEPOINT s1");
+    assertInvalidStatement("SAVEPOINT");
+    assertInvalidStatement("ROLLBACK TO s1");
+
+    assert_eq!(parseStatement("SAVEPOINT level").unwrap(), Statement::Savepoint { name: "level".to_string() });
+    assert_eq!(parseStatement("RELEASE SAVEPOINT s").unwrap(), Statement::ReleaseSavepoint { name: "s".to_string() });
+    assert_eq!(parseStatement("ROLLBACK TO SAVEPOINT s").unwrap(), Statement::RollbackToSavepoint { name: "s".to_string() });
+}
+
+#[test]
+fn testUpdate() {
+    assertStatement("UPDATE foo_table SET foo = 'bar'");
+    assertStatement("UPDATE foo_table SET foo = 'bar', baz = baz + 1 WHERE qux IS NULL");
+    assertStatement("UPDATE a.b SET c = (SELECT d FROM e)");
+    assertInvalidStatement("UPDATE foo_table SET");
+    assertInvalidStatement("UPDATE foo_table SET foo.bar = 1");
+    assertInvalidStatement("UPDATE foo_table WHERE a = 1");
+
+    assert_eq!(parseStatement("UPDATE t SET a = 1 WHERE b").unwrap(), Statement::Update {
+        table_name: QualifiedName { name: vec!["t".to_string()] },
+        assignments: vec![UpdateAssignment {
+            name: "a".to_string(),
+            value: Expression::Literal(Literal::Numeric("1".to_string()))
+        }],
+        filter: Some(Expression::Identifier { name: "b".to_string() })
+    });
+}
+
+#[test]
+fn testMerge() {
+    assertStatement("MERGE INTO inventory AS i \
+                     USING changes AS c \
+                     ON i.part = c.part \
+                     WHEN MATCHED AND c.kind = 'mod' THEN UPDATE SET qty = qty + c.qty, ts = c.ts \
+                     WHEN MATCHED AND c.kind = 'del' THEN DELETE \
+                     WHEN NOT MATCHED AND c.kind = 'new' THEN INSERT (part, qty) VALUES (c.part, c.qty)");
+    assertStatement("MERGE INTO t USING (SELECT * FROM s) x ON t.a = x.a WHEN NOT MATCHED THEN INSERT VALUES (x.a)");
+    assertStatement("MERGE INTO t USING s JOIN u ON s.a = u.a ON t.a = s.a WHEN MATCHED THEN DELETE");
+    assertInvalidStatement("MERGE INTO t USING s ON t.a = s.a");
+    assertInvalidStatement("MERGE INTO t USING s ON t.a = s.a WHEN MATCHED THEN INSERT VALUES (1)");
+    assertInvalidStatement("MERGE INTO t USING s ON t.a = s.a WHEN NOT MATCHED THEN DELETE");
+
+    let identifier = |name: &str| Expression::Identifier { name: name.to_string() };
+    assert_eq!(parseStatement("MERGE INTO t x USING s ON a WHEN MATCHED AND b THEN UPDATE SET c = d \
+                               WHEN NOT MATCHED THEN INSERT (c) VALUES (d)").unwrap(),
+               Statement::Merge {
+                   table_name: QualifiedName { name: vec!["t".to_string()] },
+                   alias: Some("x".to_string()),
+                   source: table("s"),
+                   condition: identifier("a"),
+                   cases: vec![
+                       MergeCase::Update {
+                           condition: Some(identifier("b")),
+                           assignments: vec![UpdateAssignment { name: "c".to_string(), value: identifier("d") }]
+                       },
+                       MergeCase::Insert {
+                           condition: None,
+                           columns: Some(vec![ColumnName { identifier: "c".to_string() }]),
+                           values: vec![identifier("d")]
+                       }
+                   ]
+               });
+}
+
+#[test]
+fn testStatements() {
+    let script = "CREATE TABLE t (a bigint);\n;; INSERT INTO t VALUES (?);\nSELECT a FROM t WHERE a = ? ;";
+    let statements = parseStatements(script).unwrap();
+    let ranges: Vec<&str> = statements.iter().map(|(_, range)| &script[range.clone()]).collect();
+    assert_eq!(ranges, vec!["CREATE TABLE t (a bigint)", "INSERT INTO t VALUES (?)", "SELECT a FROM t WHERE a = ?"]);
+    // parameter positions restart with each statement
+    for (statement, range) in statements {
+        assert_eq!(statement, parseStatement(&script[range]).unwrap());
+    }
+
+    assert_eq!(parseStatements("").unwrap(), vec![]);
+    assert_eq!(parseStatements(" ; -- nothing\n;").unwrap(), vec![]);
+    assert_eq!(parseStatements("SELECT 1").unwrap().len(), 1);
+    assert!(parseStatements("SELECT 1 SELECT 2").is_err());
+    assert!(parseStatements("SELECT 1; SELECT").is_err());
+    assert!(matches!(parseStatements("SELECT 1; SELECT 'a"),
+                     Err(ParseError::User { error: token::Error { code: ErrorCode::UnterminatedLiteral, .. } })));
+}
+
+#[test]
+fn testCoalesce() {
+    let number = |value: &str| Expression::Literal(Literal::Numeric(value.to_string()));
+    let null = || Expression::Literal(Literal::Null);
+
+    assertInvalidExpression("coalesce()");
+    assertInvalidExpression("coalesce(5)");
+    assert_eq!(parseExpression("coalesce(13, 42)").unwrap(), Expression::Coalesce(vec![number("13"), number("42")]));
+    assert_eq!(parseExpression("coalesce(6, 7, 8)").unwrap(),
+               Expression::Coalesce(vec![number("6"), number("7"), number("8")]));
+    assert_eq!(parseExpression("coalesce(13, null)").unwrap(), Expression::Coalesce(vec![number("13"), null()]));
+    assert_eq!(parseExpression("coalesce(null, 13)").unwrap(), Expression::Coalesce(vec![null(), number("13")]));
+    assert_eq!(parseExpression("coalesce(null, null)").unwrap(), Expression::Coalesce(vec![null(), null()]));
+}
+
+#[test]
+fn testCase() {
+    assertExpression("CASE a WHEN 1 THEN b END");
+    assertExpression("CASE a WHEN 1 THEN b WHEN 2 THEN c ELSE d END");
+    assertExpression("CASE WHEN a > 1 THEN b END");
+    assertExpression("CASE WHEN a > 1 AND c THEN b WHEN a < 0 THEN -b ELSE 0 END");
+    assertExpression("CASE CASE a WHEN 1 THEN b END WHEN c THEN d END");
+    assertExpression("1 + CASE WHEN a THEN 1 ELSE 2 END * 3");
+    assertInvalidExpression("CASE a END");
+    assertInvalidExpression("CASE WHEN a THEN b");
+    assertInvalidExpression("CASE ELSE b END");
+
+    let a = || Expression::Identifier { name: "a".to_string() };
+    let b = || Expression::Identifier { name: "b".to_string() };
+    assert_eq!(parseExpression("CASE a WHEN b THEN a ELSE b END").unwrap(), Expression::SimpleCase {
+        operand: Box::new(a()),
+        when_clauses: vec![WhenClause { operand: b(), result: a() }],
+        default: Some(Box::new(b()))
+    });
+    assert_eq!(parseExpression("CASE WHEN a THEN b END").unwrap(), Expression::SearchedCase {
+        when_clauses: vec![WhenClause { operand: a(), result: b() }],
+        default: None
+    });
+}
+
+#[test]
+fn testNullIf() {
+    assertExpression("nullif(42, 87)");
+    assertExpression("NULLIF(a + 1, b)");
+    assertInvalidExpression("nullif(42)");
+    assertStatement("SELECT coalesce, nullif FROM t AS coalesce");
+    assertExpression("coalesce(coalesce, nullif.nullif)");
+    assertInvalidExpression("nullif(1, 2, 3)");
+}
+
+#[test]
+fn testIf() {
+    assertExpression("if(a, b)");
+    assertExpression("IF(a > 1, b, c)");
+    assertExpression("if(a, if(b, c, d), e)");
+    assertInvalidExpression("if(a)");
+    assertInvalidExpression("if(a, b, c, d)");
+}
+
 //@Test
 //public void testDoubleInQuery()
 //{
@@ -182,6 +1873,35 @@ fn testArithmeticUnary() {
 //table(QualifiedName.of("DUAL"))));
 //}
 
+fn table(name: &str) -> Relation {
+    Relation::Table(QualifiedName { name: name.split('.').map(str::to_string).collect() })
+}
+
+fn join(join_type: JoinType, left: Relation, right: Relation, criteria: Option<JoinCriteria>) -> Relation {
+    Relation::Join { join_type, left: Box::new(left), right: Box::new(right), criteria }
+}
+
+// Offset and limit of a query
+fn limit(sql: &str) -> (Option<Offset>, Option<Limit>) {
+    match parseStatement(sql).unwrap() {
+        Statement::Query { body: QueryBody { offset, limit, .. }, .. } => (offset, limit),
+        statement => panic!("unexpected statement {:?}", statement)
+    }
+}
+
+// Query specification of a simple query
+fn select(sql: &str) -> Select {
+    match parseStatement(sql).unwrap() {
+        Statement::Query { body: QueryBody { query_term: QueryTerm::Primary(QueryPrimary::Select(select)), .. }, .. } => select,
+        statement => panic!("unexpected statement {:?}", statement)
+    }
+}
+
+// FROM clause of a simple query
+fn from(sql: &str) -> Relation {
+    select(sql).from.expect("query without from clause")
+}
+
 fn assertGenericLiteral(type_str: &str) {
     assertExpression((type_str.to_string() + " 'abc'").as_ref());
 }
@@ -192,7 +1912,7 @@ fn assertExpression(expression: &str) {
 
 fn assertInvalidExpression(expression: &str) {
     assert!(parseExpression(expression).is_err(),
-            "Error is expected while parsing invalid expression ".to_string() + expression)
+            "{}", "Error is expected while parsing invalid expression ".to_string() + expression)
 }
 
 fn assertStatement(sql: &str) {
@@ -201,10 +1921,11 @@ fn assertStatement(sql: &str) {
 
 fn assertInvalidStatement(sql: &str) {
     assert!(parseStatement(sql).is_err(),
-            "Error expected while parsing invalid Statement ".to_string() + sql)
+            "{}", "Error expected while parsing invalid Statement ".to_string() + sql)
 }
 
 fn assertCast(type_str: &str)
 {
-    // assertExpression("CAST(null AS " + type_str + ")");
+    assertExpression(&("CAST(null AS ".to_string() + type_str + ")"));
+    assertExpression(&("TRY_CAST(null AS ".to_string() + type_str + ")"));
 }
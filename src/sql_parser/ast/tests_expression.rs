@@ -1,10 +1,8 @@
-#[cfg(test)]
-
-use super::expression::*;
 use super::expression::Expression;
 use super::expression::BinaryOperator;
 use super::expression::BooleanExpression;
-use crate::sql_parser::ast::expression::BooleanExpression::BinaryExpression;
+use crate::sql_parser::parser::{parseExpression, parseStatement, Error};
+use std::fmt::{Debug, Display};
 
 
 fn binary_expression_display(op: BinaryOperator, fmt_string: &str) {
@@ -22,6 +20,13 @@ fn binary_expression_display(op: BinaryOperator, fmt_string: &str) {
     assert_eq!(format!("{}", binary_expression).as_str(), fmt_string);
 }
 
+// Checks that `sql` prints as `printed` and that the printed form parses back to the same tree
+fn assert_round_trip<T: Debug + Display + PartialEq>(parse: fn(&str) -> Result<T, Error>, sql: &str, printed: &str) {
+    let parsed = parse(sql).unwrap();
+    assert_eq!(format!("{}", parsed).as_str(), printed);
+    assert_eq!(parse(printed).unwrap(), parsed);
+}
+
 #[test]
 fn test_binary_expression_display() {
     binary_expression_display(BinaryOperator::Greater, "a > b");
@@ -97,3 +102,272 @@ fn test_simple_binary_expression_comparision() {
     assert_eq!(binary_expression_4, binary_expression_5);
     assert_ne!(binary_expression_4, binary_expression_6);
 }
+
+#[test]
+fn test_expression_display_round_trip() {
+    let expressions = [
+        "a + b * c",
+        "(a + b) * c",
+        "a - (b - c)",
+        "a - b - c",
+        "(a = b) = c",
+        "a = b or c and not d",
+        "(a or b) and c",
+        "not (a and b)",
+        "- (a + b)",
+        "- - a",
+        "a || (b & c)",
+    ];
+    for expression in expressions.iter() {
+        assert_round_trip(parseExpression, expression, expression);
+    }
+}
+
+#[test]
+fn test_function_call_display() {
+    let expressions = [
+        "now()",
+        "count(*)",
+        "count(distinct x)",
+        "a.b.f(x, y + z)",
+        "array_agg(x order by y desc, z)",
+        "sum(x) filter (where y and z)",
+        "f(a => x, b => y)",
+    ];
+    for expression in expressions.iter() {
+        assert_round_trip(parseExpression, expression, expression);
+    }
+}
+
+#[test]
+fn test_cast_display() {
+    let expressions = [
+        ("CAST(x AS bigint)", "cast(x as bigint)"),
+        ("TRY_CAST(x AS varchar(42))", "try_cast(x as varchar(42))"),
+        ("CAST(x AS bigint ARRAY)", "cast(x as array(bigint))"),
+        ("CAST(x AS map<varchar, array<double>>)", "cast(x as map(varchar, array(double)))"),
+        ("CAST(x AS ROW(a bigint, b DOUBLE PRECISION))", "cast(x as row(a bigint, b double precision))"),
+        ("CAST(x AS timestamp with time zone)", "cast(x as timestamp with time zone)"),
+        ("CAST(x AS INTERVAL YEAR TO MONTH)", "cast(x as interval year to month)"),
+    ];
+    for (expression, expected) in expressions.iter() {
+        assert_round_trip(parseExpression, expression, expected);
+    }
+}
+
+#[test]
+fn test_conditional_display() {
+    let expressions = [
+        "case a when b then c when d then e else f end",
+        "case when a and b then c end",
+        "coalesce(a, b, c)",
+        "nullif(a, b)",
+        "if(a, b)",
+        "if(a = b, c, d)",
+    ];
+    for expression in expressions.iter() {
+        assert_round_trip(parseExpression, expression, expression);
+    }
+}
+
+#[test]
+fn test_constructor_display() {
+    let expressions = [
+        "array[a, b][c]",
+        "array[]",
+        "map(array[a], array[b])",
+        "row(a, b + c)",
+        "a.b.c",
+        "f(x).a[b]",
+        "(a + b)[c]",
+        "(case when a then b end).c",
+    ];
+    for expression in expressions.iter() {
+        assert_round_trip(parseExpression, expression, expression);
+    }
+}
+
+#[test]
+fn test_literal_display() {
+    let expressions = [
+        "1",
+        "1.5e10",
+        "'it''s'",
+        "X'ABCD'",
+        "null",
+        "true",
+        "current_date",
+        "date '2012-01-01'",
+        "time '00:01:02'",
+        "timestamp '2012-01-01 00:01:02'",
+        "char 'abc'",
+        "varchar 'abc'",
+        "interval '3' year",
+        "interval -'3' day to second",
+        "- 1 + 2 * 3",
+    ];
+    for expression in expressions.iter() {
+        assert_round_trip(parseExpression, expression, expression);
+    }
+}
+
+#[test]
+fn test_lambda_display() {
+    let expressions = [
+        "transform(arr, x -> x + 1)",
+        "filter(arr, x -> x > 0)",
+        "filter(arr, x -> x > 0) over (partition by a)",
+        "reduce(arr, 0, (s, x) -> s + x, s -> s)",
+        "transform(arr, x -> transform(x, y -> x.a[y]))",
+    ];
+    for expression in expressions.iter() {
+        assert_round_trip(parseExpression, expression, expression);
+    }
+}
+
+#[test]
+fn test_predicate_display() {
+    let expressions = [
+        "a between b and c",
+        "a not between b + 1 and c",
+        "(a = b) between c and d",
+        "a in (1, 2, 3)",
+        "a not in ('x')",
+        "a like 'b%'",
+        "a not like 'b!%' escape '!'",
+        "a is null",
+        "a is not null",
+        "a is distinct from b",
+        "a is not distinct from b",
+        "not a is null and b between c and d",
+        "(a is null) = (b is null)",
+    ];
+    for expression in expressions.iter() {
+        assert_round_trip(parseExpression, expression, expression);
+    }
+}
+
+#[test]
+fn test_subquery_display() {
+    let expressions = [
+        "a in (select b from t where c = 1)",
+        "not exists (select b from t)",
+        "a < any (select b from t)",
+        "a = all (select b from t union select c from u)",
+        "(select a from t) + 1",
+    ];
+    for expression in expressions.iter() {
+        assert_round_trip(parseExpression, expression, expression);
+    }
+}
+
+#[test]
+fn test_query_display() {
+    let statements = [
+        "select 1",
+        "select distinct a, b as c from t where a > 1",
+        "select a from db.t as x (b, c)",
+        "select a from (select b from t) as x",
+        "select a from t, u, v",
+        "select a from t left join u on t.a = u.b inner join v using (c, d)",
+        "select a from t natural full join u",
+        "select a from t inner join (u right join v on x) on y",
+        "select a from (t inner join u on x) as w",
+        "select a from t where a in (select b from u) union select c from v",
+        "select a, count(*) from t group by a having count(*) > 1",
+        "select a from t group by distinct a, (a, b), (), rollup (a, b), cube (c), grouping sets (a, (b, c), ())",
+        "select grouping(a, t.b) from t group by cube (a, t.b)",
+        "select lag(x, 1) over (partition by a order by b desc rows between 2 preceding and current row) from t",
+        "select sum(x) filter (where x > 0) over w from t window w as (partition by a, b)",
+        "select count(*) over (w range unbounded preceding) from t window w as (order by a), v as ()",
+        "select sum(x) over (groups between (a = b) following and unbounded following) from t",
+        "values (1, 'a'), (2, 'b')",
+        "select * from (values (1, 2), (3, 4)) as v (a, b)",
+        "select t.*, u.a from t, u",
+        "insert into t (a) values (1), (2)",
+        "select * from t cross join unnest(a, b) with ordinality as u (x, y, i)",
+        "select * from t, lateral (select * from u where u.a = t.a) as l",
+        "select * from t as x tablesample bernoulli (10) cross join u tablesample system (5)",
+        "select * from (t tablesample system (5)) as x",
+        "select a from t union all select b from u intersect select c from v",
+        "(select a from t) except (select b from u order by b limit 1) order by 1",
+        "select a from t intersect (select b from u union select c from v)",
+        "select * from ((select a from t) union (select b from u)) as x",
+        "select ((select 1) union select 2)",
+        "select a from t where a in ((select 1) except (select 2) order by 1)",
+        "select a from t limit all",
+        "select a from t order by a limit 10 offset 5",
+        "select a from t limit 5, 10",
+        "select a from t offset 2 limit 3",
+        "select a from t order by a offset 2 rows fetch next 3 rows only",
+        "select a from t offset 1 row fetch first row with ties",
+        "update t set a = 1, b = b || 'x' where c in (1, 2)",
+        "merge into t as x using s on x.a = s.a when matched and s.b then update set c = s.c when matched then delete \
+         when not matched then insert (a, c) values (s.a, s.c)",
+        "merge into t using (select * from s) as u on t.a = u.a when not matched and u.b > 0 then insert values (u.a)",
+    ];
+    for statement in statements.iter() {
+        assert_round_trip(parseStatement, statement, statement);
+    }
+}
+
+#[test]
+fn test_statement_display() {
+    let statements = [
+        "create schema if not exists a.b",
+        "drop schema a cascade",
+        "create table if not exists t (a bigint, b varchar)",
+        "create table t (a bigint not null default 1 comment 'a' with (x = 'y'), b time with time zone)",
+        "create table t (a boolean not null default (b is null), c varchar default 'x' || 'y')",
+        "create table t (like u including properties, c row(x bigint, y varchar), primary key (c), check (c > 0))",
+        "create table t (a bigint, unique (a)) comment 't' with (format = 'CSV', partitioned_by = array['ds'])",
+        "create table t (x, y) as select a, b from u",
+        "create table t comment 't' with (format = 'ORC') as select a from u with no data",
+        "create table t as select * from unnest(a) with ordinality with data",
+        "create table t as (select * from unnest(a)) with no data",
+        "create table t as select * from unnest(a) with no data",
+        "drop table if exists a.b",
+        "alter table if exists a.b rename to a.c",
+        "alter table t add column if not exists c bigint not null with (x = 1)",
+        "alter table t drop column if exists c",
+        "alter table if exists t rename column if exists a to b",
+        "alter table t alter column a set data type map(varchar, bigint)",
+        "alter table t set properties format = 'ORC', partitioned_by = array['ds']",
+        "create view v as select * from t",
+        "create or replace view a.v (x, y) as select a, b from t",
+        "drop view if exists a.v",
+        "alter view a.v rename to b.w",
+        "create or replace materialized view if not exists m as select a from t",
+        "refresh materialized view a.m",
+        "start transaction",
+        "start transaction isolation level read committed, read only",
+        "start transaction read write, isolation level serializable",
+        "begin",
+        "commit",
+        "rollback",
+        "savepoint s",
+        "release savepoint s",
+        "rollback to savepoint s",
+        "explain select a from t",
+        "explain analyze verbose (type distributed, format json) select a from t",
+        "explain (format graphviz) explain analyze delete from t",
+        "show catalogs like 'a%' escape '$'",
+        "show schemas from c",
+        "show tables from c.s like 't%'",
+        "show columns from t",
+        "show functions",
+        "show create materialized view a.m",
+        "show session like 'q%'",
+        "show stats for a.b",
+        "describe a.b",
+        "prepare p from select a from t where b = ? and c = :c",
+        "execute p using 1, 'a'",
+        "execute p",
+        "deallocate prepare p",
+        "describe input p",
+        "describe output p",
+    ];
+    for statement in statements.iter() {
+        assert_round_trip(parseStatement, statement, statement);
+    }
+}
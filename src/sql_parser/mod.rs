@@ -1,7 +1,4 @@
-use lalrpop_util;
-
-#[allow(dead_code)]
-lalrpop_mod!(sql, "/sql_parser/sql.rs");
+lalrpop_mod!(#[allow(dead_code, unused, clippy::all)] sql, "/sql_parser/sql.rs");
 
 pub mod ast;
 pub mod token;
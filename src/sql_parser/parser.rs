@@ -1,22 +1,54 @@
-use crate::sql_parser::ast::basic_ast::{Query, Statement};
-use crate::sql_parser::token::Tokenizer;
-use crate::sql_parser::token::Tok;
+use crate::sql_parser::ast::basic_ast::Statement;
+use crate::sql_parser::token::{Tok, Tokenizer};
 use super::sql::{ExpressionParser, StatementParser};
 use lalrpop_util;
-use crate::sql_parser::ast::expression::Expression;
+use std::cell::RefCell;
+use std::ops::Range;
+use crate::sql_parser::ast::expression::{Expression, ParameterPositions};
 
 pub type Error<'input> = lalrpop_util::ParseError<usize, crate::sql_parser::token::Tok<'input>, crate::sql_parser::token::Error>;
 
-pub fn parseStatement(input: &str) -> Result<Statement, Error> {
+#[allow(non_snake_case)]
+pub fn parseStatement(input: &str) -> Result<Statement, Error<'_>> {
     let tokenizer = Tokenizer::new(input, 0);
-    let sql = StatementParser::new().parse(input, tokenizer)?;
+    let parameters = RefCell::new(ParameterPositions::default());
+    let sql = StatementParser::new().parse(input, &parameters, tokenizer)?;
 
     Ok(sql)
 }
 
-pub fn parseExpression(input: &str) -> Result<Expression, Error> {
+// Parses a script of statements separated by semicolons, with the byte range of each statement.
+// Empty statements, a trailing semicolon included, are skipped.
+#[allow(non_snake_case)]
+pub fn parseStatements(input: &str) -> Result<Vec<(Statement, Range<usize>)>, Error<'_>> {
+    let mut statements = vec![];
+    let mut tokens = vec![];
+    let mut tokenizer = Tokenizer::new(input, 0);
+    loop {
+        let token = tokenizer.next();
+        match token {
+            Some(Ok((_, Tok::Semi, _))) | None => {
+                if let (Some(Ok((start, _, _))), Some(Ok((_, _, end)))) = (tokens.first(), tokens.last()) {
+                    let range = *start..*end;
+                    let parameters = RefCell::new(ParameterPositions::default());
+                    let statement = StatementParser::new().parse(input, &parameters, tokens.drain(..))?;
+                    statements.push((statement, range));
+                }
+                if token.is_none() {
+                    return Ok(statements);
+                }
+            }
+            Some(Ok(token)) => tokens.push(Ok(token)),
+            Some(Err(error)) => return Err(lalrpop_util::ParseError::User { error })
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn parseExpression(input: &str) -> Result<Expression, Error<'_>> {
     let tokenizer = Tokenizer::new(input, 0);
-    let sql_expression = ExpressionParser::new().parse(input, tokenizer)?;
+    let parameters = RefCell::new(ParameterPositions::default());
+    let sql_expression = ExpressionParser::new().parse(input, &parameters, tokenizer)?;
 
     Ok(sql_expression)
 }
@@ -21,18 +21,21 @@ pub enum ErrorCode {
     UnterminatedBracket,
     UnterminatedBlockComment,
     BadVariableName,
+    BadLambdaArgument,
     BadNumber,
     ExpectedEqualsSign,
     MalformedBlobLiteral,
     MalformedHexInteger,
+    DuplicateColumnOption,
+    MixedParameters,
 }
 
-fn error<T>(c: ErrorCode, l: usize, t: &str) -> Result<T, Error> {
+pub(crate) fn error<T>(c: ErrorCode, l: usize, t: &str) -> Result<T, Error> {
     let line = t[..l].chars().filter(|c| *c == '\n').count() + 1;
     Err(Error {
         location: l,
         code: c,
-        line: line,
+        line,
     })
 }
 
@@ -41,6 +44,9 @@ pub struct Tokenizer<'input> {
     chars: CharIndices<'input>,
     lookahead: Option<(usize, char)>,
     shift: usize,
+    // `[` opens an array constructor or a subscript right after `ARRAY` or an operand
+    // (`a[1]`, `day[1]`, `'abc'[1]`, `f(x)[1]`, `a[1][2]`) and a bracketed identifier (`[my column]`) anywhere else
+    bracket_is_symbol: bool,
 }
 
 pub type Spanned<T> = (usize, T, usize);
@@ -56,7 +62,7 @@ pub enum Tok<'input> {
     Alter,
     Analyze,
     And,
-    // Any,
+    Any,
     Array,
     As,
     Asc,
@@ -64,30 +70,43 @@ pub enum Tok<'input> {
     Autoincr,
     Before,
     Begin,
+    Bernoulli,
     Between,
     By,
     Cascade,
     Case,
     Cast,
+    Catalogs,
     Check,
+    Coalesce,
     Collate,
     ColumnKw,
+    Columns,
+    Comment,
     Commit,
+    Committed,
     Conflict,
     Constraint,
     Create,
     Cross,
+    Cube,
+    Current,
     CurrentDate,
     CurrentTime,
     CurrentTimestamp,
+    Data,
     Database,
+    Day,
+    Deallocate,
     Default,
     Deferrable,
     Deferred,
     Delete,
     Desc,
+    Describe,
     Detach,
     Distinct,
+    Distributed,
     Double,
     Drop,
     Each,
@@ -95,97 +114,168 @@ pub enum Tok<'input> {
     End,
     Escape,
     Except,
+    Excluding,
     Exclusive,
+    Execute,
     Exists,
     Explain,
     Fail,
+    False,
+    Fetch,
+    Filter,
     First,
+    Following,
     For,
     Foreign,
+    Format,
     From,
     Full,
     // Function,
+    Functions,
     Glob,
+    Graphviz,
     Group,
+    Grouping,
+    Groups,
     Having,
+    Hour,
     If,
     Ignore,
     Immediate,
     In,
+    Including,
     Index,
     Indexed,
     Initially,
     Inner,
+    Input,
     Insert,
     Instead,
     Intersect,
+    Interval,
     Into,
+    Io,
     Is,
     // IsNot,
     IsNull,
+    Isolation,
     Join,
+    Json,
     Key,
     Last,
+    Lateral,
     Left,
+    Level,
     Like,
     Limit,
+    Logical,
     Match,
     Map,
+    Matched,
+    Materialized,
+    Merge,
+    Minute,
+    Month,
     Natural,
+    Next,
     No,
     Not,
     NotNull,
     Null,
+    Nullif,
     Of,
     Offset,
     On,
+    Only,
     Or,
     Order,
+    Ordinality,
     Outer,
+    Output,
+    Over,
+    Partition,
     Plan,
     Pragma,
+    Preceding,
     Precision,
+    Prepare,
     Primary,
+    Properties,
     Query,
     Raise,
+    Range,
+    Read,
     Recursive,
     References,
+    Refresh,
     Regexp,
     Reindex,
     Release,
     Rename,
+    Repeatable,
     Replace,
     Restrict,
     Right,
     Rollback,
+    Rollup,
     Row,
+    Rows,
     Savepoint,
     Schema,
+    Schemas,
+    Second,
     Select,
+    Serializable,
+    Session,
     Set,
+    Sets,
+    Show,
+    SomeKw,
+    Start,
+    Stats,
+    System,
     Table,
+    Tables,
+    Tablesample,
     Temp,
+    Text,
     Then,
+    Ties,
     Time,
     Timestamp,
     To,
     Transaction,
     Trigger,
+    True,
+    TryCast,
+    Type,
+    Unbounded,
+    Uncommitted,
     Union,
     Unique,
+    Unnest,
     Update,
     Use,
     Using,
     Vacuum,
+    Validate,
     Values,
+    Verbose,
     View,
     Virtual,
     When,
     Where,
+    Window,
     With,
     Without,
+    Work,
+    Write,
+    Year,
     Zone,
 
+    // `WITH` of `WITH [NO] DATA`, told apart so that it does not compete with `WITH ORDINALITY`
+    WithData,
+
     // Identifiers:
     StringLiteral(&'input str),
     Id(&'input str),
@@ -197,15 +287,18 @@ pub enum Tok<'input> {
     Float(&'input str),
 
     // Symbols:
+    Arrow,
     BitAnd,
     BitNot,
     BitOr,
     Comma,
     Concat,
     Dot,
+    DoubleArrow,
     Equals,
     GreaterThan,
     GreaterEquals,
+    LeftBracket,
     LeftParen,
     LeftShift,
     LessEquals,
@@ -214,6 +307,7 @@ pub enum Tok<'input> {
     NotEquals,
     Plus,
     Reminder,
+    RightBracket,
     RightParen,
     RightShift,
     Semi,
@@ -221,8 +315,8 @@ pub enum Tok<'input> {
     Star,
 }
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
-const KEYWORDS: &'static [(&'static str, Tok<'static>)] = &[
+#[rustfmt::skip]
+const KEYWORDS: &[(&str, Tok<'static>)] = &[
     ("ABORT", Abort),
     ("ACTION", Action),
     ("ADD", Add),
@@ -231,6 +325,7 @@ const KEYWORDS: &'static [(&'static str, Tok<'static>)] = &[
     ("ALTER", Alter),
     ("ANALYZE", Analyze),
     ("AND", And),
+    ("ANY", Any),
     ("ARRAY", Array),
     ("AS", As),
     ("ASC", Asc),
@@ -238,30 +333,43 @@ const KEYWORDS: &'static [(&'static str, Tok<'static>)] = &[
     ("AUTOINCREMENT", Autoincr),
     ("BEFORE", Before),
     ("BEGIN", Begin),
+    ("BERNOULLI", Bernoulli),
     ("BETWEEN", Between),
     ("BY", By),
     ("CASCADE", Cascade),
     ("CASE", Case),
     ("CAST", Cast),
+    ("CATALOGS", Catalogs),
     ("CHECK", Check),
+    ("COALESCE", Coalesce),
     ("COLLATE", Collate),
     ("COLUMN", ColumnKw),
+    ("COLUMNS", Columns),
+    ("COMMENT", Comment),
     ("COMMIT", Commit),
+    ("COMMITTED", Committed),
     ("CONFLICT", Conflict),
     ("CONSTRAINT", Constraint),
     ("CREATE", Create),
     ("CROSS", Cross),
+    ("CUBE", Cube),
+    ("CURRENT", Current),
     ("CURRENT_DATE", CurrentDate),
     ("CURRENT_TIME", CurrentTime),
     ("CURRENT_TIMESTAMP", CurrentTimestamp),
+    ("DATA", Data),
     ("DATABASE", Database),
+    ("DAY", Day),
+    ("DEALLOCATE", Deallocate),
     ("DEFAULT", Default),
     ("DEFERRABLE", Deferrable),
     ("DEFERRED", Deferred),
     ("DELETE", Delete),
     ("DESC", Desc),
+    ("DESCRIBE", Describe),
     ("DETACH", Detach),
     ("DISTINCT", Distinct),
+    ("DISTRIBUTED", Distributed),
     ("DOUBLE", Double),
     ("DROP", Drop),
     ("EACH", Each),
@@ -269,104 +377,173 @@ const KEYWORDS: &'static [(&'static str, Tok<'static>)] = &[
     ("END", End),
     ("ESCAPE", Escape),
     ("EXCEPT", Except),
+    ("EXCLUDING", Excluding),
     ("EXCLUSIVE", Exclusive),
+    ("EXECUTE", Execute),
     ("EXISTS", Exists),
     ("EXPLAIN", Explain),
     ("FAIL", Fail),
+    ("FALSE", False),
+    ("FETCH", Fetch),
+    ("FILTER", Filter),
     ("FIRST", First),
+    ("FOLLOWING", Following),
     ("FOR", For),
     ("FOREIGN", Foreign),
+    ("FORMAT", Format),
     ("FROM", From),
     ("FULL", Full),
+    ("FUNCTIONS", Functions),
     ("GLOB", Glob),
+    ("GRAPHVIZ", Graphviz),
     ("GROUP", Group),
+    ("GROUPING", Grouping),
+    ("GROUPS", Groups),
     ("HAVING", Having),
+    ("HOUR", Hour),
     ("IF", If),
     ("IGNORE", Ignore),
     ("IMMEDIATE", Immediate),
     ("IN", In),
+    ("INCLUDING", Including),
     ("INDEX", Index),
     ("INDEXED", Indexed),
     ("INITIALLY", Initially),
     ("INNER", Inner),
+    ("INPUT", Input),
     ("INSERT", Insert),
     ("INSTEAD", Instead),
     ("INTERSECT", Intersect),
+    ("INTERVAL", Interval),
     ("INTO", Into),
+    ("IO", Io),
     ("IS", Is),
     ("ISNULL", IsNull),
+    ("ISOLATION", Isolation),
     ("JOIN", Join),
+    ("JSON", Json),
     ("KEY", Key),
     ("LAST", Last),
+    ("LATERAL", Lateral),
     ("LEFT", Left),
+    ("LEVEL", Level),
     ("LIKE", Like),
     ("LIMIT", Limit),
+    ("LOGICAL", Logical),
     ("MATCH", Match),
     ("MAP", Map),
+    ("MATCHED", Matched),
+    ("MATERIALIZED", Materialized),
+    ("MERGE", Merge),
+    ("MINUTE", Minute),
+    ("MONTH", Month),
     ("NATURAL", Natural),
+    ("NEXT", Next),
     ("NO", No),
     ("NOT", Not),
     ("NOTNULL", NotNull),
     ("NULL", Null),
+    ("NULLIF", Nullif),
     ("OF", Of),
     ("OFFSET", Offset),
     ("ON", On),
+    ("ONLY", Only),
     ("OR", Or),
     ("ORDER", Order),
+    ("ORDINALITY", Ordinality),
     ("OUTER", Outer),
+    ("OUTPUT", Output),
+    ("OVER", Over),
+    ("PARTITION", Partition),
     ("PLAN", Plan),
     ("PRAGMA", Pragma),
+    ("PRECEDING", Preceding),
     ("PRECISION", Precision),
+    ("PREPARE", Prepare),
     ("PRIMARY", Primary),
+    ("PROPERTIES", Properties),
     ("QUERY", Query),
     ("RAISE", Raise),
+    ("RANGE", Range),
+    ("READ", Read),
     ("RECURSIVE", Recursive),
     ("REFERENCES", References),
+    ("REFRESH", Refresh),
     ("REGEXP", Regexp),
     ("REINDEX", Reindex),
     ("RELEASE", Release),
     ("RENAME", Rename),
+    ("REPEATABLE", Repeatable),
     ("REPLACE", Replace),
     ("RESTRICT", Restrict),
     ("RIGHT", Right),
     ("ROLLBACK", Rollback),
+    ("ROLLUP", Rollup),
     ("ROW", Row),
+    ("ROWS", Rows),
     ("SAVEPOINT", Savepoint),
     ("SCHEMA", Schema),
+    ("SCHEMAS", Schemas),
+    ("SECOND", Second),
     ("SELECT", Select),
+    ("SERIALIZABLE", Serializable),
+    ("SESSION", Session),
     ("SET", Set),
+    ("SETS", Sets),
+    ("SHOW", Show),
+    ("SOME", SomeKw),
+    ("START", Start),
+    ("STATS", Stats),
+    ("SYSTEM", System),
     ("TABLE", Table),
+    ("TABLES", Tables),
+    ("TABLESAMPLE", Tablesample),
     ("TEMP", Temp),
     ("TEMPORARY", Temp),
+    ("TEXT", Text),
     ("THEN", Then),
+    ("TIES", Ties),
     ("TIME", Time),
     ("TIMESTAMP", Timestamp),
     ("TO", To),
     ("TRANSACTION", Transaction),
     ("TRIGGER", Trigger),
+    ("TRUE", True),
+    ("TRY_CAST", TryCast),
+    ("TYPE", Type),
+    ("UNBOUNDED", Unbounded),
+    ("UNCOMMITTED", Uncommitted),
     ("UNION", Union),
     ("UNIQUE", Unique),
+    ("UNNEST", Unnest),
     ("UPDATE", Update),
     ("USE", Use),
     ("USING", Using),
     ("VACUUM", Vacuum),
+    ("VALIDATE", Validate),
     ("VALUES", Values),
+    ("VERBOSE", Verbose),
     ("VIEW", View),
     ("VIRTUAL", Virtual),
     ("WHEN", When),
     ("WHERE", Where),
+    ("WINDOW", Window),
     ("WITH", With),
     ("WITHOUT", Without),
+    ("WORK", Work),
+    ("WRITE", Write),
+    ("YEAR", Year),
     ("ZONE", Zone)
     ];
 
 impl<'input> Tokenizer<'input> {
     pub fn new(text: &'input str, shift: usize) -> Tokenizer<'input> {
         let mut t = Tokenizer {
-            text: text,
+            text,
             chars: text.char_indices(),
             lookahead: None,
-            shift: shift,
+            shift,
+            bracket_is_symbol: false,
         };
         t.bump();
         t
@@ -385,6 +562,10 @@ impl<'input> Tokenizer<'input> {
                         self.take_until(|c| c == '\n');
                         continue;
                     }
+                    Some((idx1, '>')) => {
+                        self.bump();
+                        Some(Ok((idx0, Arrow, idx1 + 1)))
+                    }
                     _ => Some(Ok((idx0, Minus, idx0 + 1))),
                 },
                 Some((idx0, '(')) => {
@@ -425,6 +606,10 @@ impl<'input> Tokenizer<'input> {
                         self.bump();
                         Some(Ok((idx0, Equals, idx1 + 1)))
                     }
+                    Some((idx1, '>')) => {
+                        self.bump();
+                        Some(Ok((idx0, DoubleArrow, idx1 + 1)))
+                    }
                     _ => Some(Ok((idx0, Equals, idx0 + 1))),
                 },
                 Some((idx0, '<')) => match self.bump() {
@@ -481,14 +666,22 @@ impl<'input> Tokenizer<'input> {
                 }
                 Some((idx0, c)) if c == '`' || c == '\'' || c == '"' => Some(self.literal(idx0, c)),
                 Some((idx0, '.')) => match self.bump() {
-                    Some((_, c)) if c.is_digit(10) => Some(self.fractional_part(idx0)),
+                    Some((_, c)) if c.is_ascii_digit() => Some(self.fractional_part(idx0)),
                     _ => Some(Ok((idx0, Dot, idx0 + 1))),
                 },
-                Some((idx0, c)) if c.is_digit(10) => Some(self.number(idx0, c)),
+                Some((idx0, c)) if c.is_ascii_digit() => Some(self.number(idx0, c)),
+                Some((idx0, '[')) if self.bracket_is_symbol => {
+                    self.bump();
+                    Some(Ok((idx0, LeftBracket, idx0 + 1)))
+                }
                 Some((idx0, '[')) => Some(self.bracket(idx0)),
+                Some((idx0, ']')) => {
+                    self.bump();
+                    Some(Ok((idx0, RightBracket, idx0 + 1)))
+                }
                 Some((idx0, '?')) => {
                     self.bump();
-                    let num = match self.take_while_1(|c| c.is_digit(10)) {
+                    let num = match self.take_while_1(|c| c.is_ascii_digit()) {
                         (false, _) => (idx0, Variable(""), idx0 + 1),
                         (true, Some((end, _))) => (idx0, Variable(&self.text[idx0..end]), end), /* '?' is included as part of the name */
                         (true, None) => (idx0, Variable(&self.text[idx0..]), self.text.len()),
@@ -568,7 +761,7 @@ impl<'input> Tokenizer<'input> {
         let mut n = 0;
         loop {
             match self.bump() {
-                Some((_, c)) if c.is_digit(16) => {
+                Some((_, c)) if c.is_ascii_hexdigit() => {
                     n += 1;
                 }
                 Some((idx1, '\'')) if n % 2 == 0 => {
@@ -587,7 +780,7 @@ impl<'input> Tokenizer<'input> {
 
     // Real
     fn fractional_part(&mut self, idx0: usize) -> Result<Spanned<Tok<'input>>, Error> {
-        match self.take_while(|c| c.is_digit(10)) {
+        match self.take_while(|c| c.is_ascii_digit()) {
             Some((end, c)) => {
                 if c == 'e' || c == 'E' {
                     self.exponential_part(idx0)
@@ -611,7 +804,7 @@ impl<'input> Tokenizer<'input> {
             _ => {}
         };
 
-        match self.take_while_1(|c| c.is_digit(10)) {
+        match self.take_while_1(|c| c.is_ascii_digit()) {
             (false, _) => error(BadNumber, idx0, self.text),
             (true, Some((end, c))) => {
                 if is_identifier_start(c) {
@@ -636,7 +829,7 @@ impl<'input> Tokenizer<'input> {
                 _ => {}
             }
         }
-        match self.take_while(|c| c.is_digit(10)) {
+        match self.take_while(|c| c.is_ascii_digit()) {
             Some((end, c)) => {
                 if c == '.' {
                     self.bump();
@@ -656,7 +849,7 @@ impl<'input> Tokenizer<'input> {
 
     fn hex_integer(&mut self, idx0: usize) -> Result<Spanned<Tok<'input>>, Error> {
         // Must not be empty (Ox is invalid)
-        match self.take_while_1(|c| c.is_digit(16)) {
+        match self.take_while_1(|c| c.is_ascii_hexdigit()) {
             (false, _) => {
                 self.word(idx0);
                 error(MalformedHexInteger, idx0, self.text)
@@ -679,12 +872,40 @@ impl<'input> Tokenizer<'input> {
         let tok = KEYWORDS
             .iter()
             .filter(|&&(w, _)| w.eq_ignore_ascii_case(word))
-            .map(|&(_, ref t)| t.clone())
+            .map(|(_, t)| t.clone())
             .next()
-            .unwrap_or_else(|| Id(word));
+            .unwrap_or(Id(word));
+        let tok = match tok {
+            Interval if !self.opens_interval(end) => Id(word),
+            With if self.opens_with_data(end) => WithData,
+            tok => tok,
+        };
         Ok((start, tok, end))
     }
 
+    // INTERVAL is non-reserved, it is only a keyword when an interval literal or type follows it
+    fn opens_interval(&self, end: usize) -> bool {
+        let mut rest = Tokenizer::new(&self.text[end..], 0);
+        match rest.next_unshifted() {
+            Some(Ok((_, StringLiteral(_), _))) | Some(Ok((_, Year, _))) | Some(Ok((_, Month, _))) |
+            Some(Ok((_, Day, _))) | Some(Ok((_, Hour, _))) | Some(Ok((_, Minute, _))) |
+            Some(Ok((_, Second, _))) => true,
+            Some(Ok((_, Plus, _))) | Some(Ok((_, Minus, _))) => matches!(rest.next_unshifted(), Some(Ok((_, StringLiteral(_), _)))),
+            _ => false,
+        }
+    }
+
+    // `WITH NO DATA`, or `WITH DATA` unless it starts a query named `data`
+    // (`WITH data AS (...)` or `WITH data (a, b) AS (...)`)
+    fn opens_with_data(&self, end: usize) -> bool {
+        let mut rest = Tokenizer::new(&self.text[end..], 0);
+        match rest.next_unshifted() {
+            Some(Ok((_, No, _))) => true,
+            Some(Ok((_, Data, _))) => !matches!(rest.next_unshifted(), Some(Ok((_, As, _))) | Some(Ok((_, LeftParen, _)))),
+            _ => false,
+        }
+    }
+
     fn word(&mut self, idx0: usize) -> Spanned<&'input str> {
         match self.take_while(is_identifier_continue) {
             Some((end, _)) => (idx0, &self.text[idx0..end], end),
@@ -791,29 +1012,46 @@ impl<'input> Iterator for Tokenizer<'input> {
     fn next(&mut self) -> Option<Result<Spanned<Tok<'input>>, Error>> {
         match self.next_unshifted() {
             None => None,
-            Some(Ok((l, t, r))) => Some(Ok((l + self.shift, t, r + self.shift))),
+            Some(Ok((l, t, r))) => {
+                self.bracket_is_symbol = matches!(t, Array | Id(_) | StringLiteral(_) | RightParen | RightBracket)
+                    || is_non_reserved(&t);
+                Some(Ok((l + self.shift, t, r + self.shift)))
+            }
             Some(Err(Error {
                 location,
                 code,
                 line,
             })) => Some(Err(Error {
                 location: location + self.shift,
-                code: code,
-                line: line,
+                code,
+                line,
             })),
         }
     }
 }
 
+// Keywords that the grammar also accepts as identifiers, see `Identifier` in sql.lalrpop
+fn is_non_reserved(t: &Tok) -> bool {
+    matches!(t,
+        Filter | Year | Month | Day | Hour | Minute | Second | Sets | Partition | Ordinality | Bernoulli | System |
+        Next | Only | Ties | Matched | Merge | Materialized | Refresh | Start | Isolation | Level | Read |
+        Write | Work | Uncommitted | Committed | Repeatable | Serializable | Verbose | Type | Format |
+        Logical | Distributed | Validate | Io | Text | Graphviz | Json | Catalogs | Columns | Describe |
+        Functions | Schemas | Session | Show | Stats | Tables | Prepare | Execute | Deallocate | Input |
+        Output | Comment | Data | Excluding | Including | Properties | Cube | Rollup | Grouping |
+        Range | Rows | Groups | Over | Window | Current | Preceding | Following | Unbounded |
+        Lateral | Unnest | Tablesample | Any | SomeKw | TryCast | Coalesce | Nullif | Fetch)
+}
+
 fn is_identifier_start(c: char) -> bool {
-    (c >= 'A' && c <= 'Z') || c == '_' || (c >= 'a' && c <= 'z') || c > '\x7F'
+    c.is_ascii_uppercase() || c == '_' || c.is_ascii_lowercase() || c > '\x7F'
 }
 
 fn is_identifier_continue(c: char) -> bool {
     c == '$'
-        || (c >= '0' && c <= '9')
-        || (c >= 'A' && c <= 'Z')
+        || c.is_ascii_digit()
+        || c.is_ascii_uppercase()
         || c == '_'
-        || (c >= 'a' && c <= 'z')
+        || c.is_ascii_lowercase()
         || c > '\x7F'
 }